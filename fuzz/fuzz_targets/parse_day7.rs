@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc2024::days::Day7;
+use aoc2024::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    // malformed input has to be rejected with an error, never a panic
+    let _ = Day7.parse(input);
+});
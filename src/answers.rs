@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::solution::Part;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl PartAnswers {
+    fn get(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn get_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Known answers, keyed by day, then by input file, then by part.
+///
+/// Stored as TOML, for example:
+///
+/// ```toml
+/// [day07."inputs/day07.txt"]
+/// part1 = "3749"
+/// part2 = "11387"
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+/// The result of comparing an answer with the stored one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+impl Answers {
+    /// Loads the answers stored at `path`, which is treated as empty if it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(Error::io(path, e)),
+        };
+
+        toml::from_str(&contents).map_err(|e| {
+            let message = format!("invalid answers file {}: {}", path.display(), e.message());
+
+            match e.span() {
+                Some(span) => Error::parse_at(&contents, &contents[span.start..], message),
+                None => Error::parse(message),
+            }
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(self).expect("answers are always serializable");
+        fs::write(path, contents).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn get(&self, day: u32, input: &str, part: Part) -> Option<&str> {
+        self.days
+            .get(&day_key(day))?
+            .get(input)?
+            .get(part)
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u32, input: &str, part: Part, answer: String) {
+        let answers = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default();
+
+        *answers.get_mut(part) = Some(answer);
+    }
+
+    /// Adds an entry with no answers yet for `day` and `input`, unless there is one already.
+    ///
+    /// Returns whether the entry was added.
+    pub fn add_placeholder(&mut self, day: u32, input: &str) -> bool {
+        let inputs = self.days.entry(day_key(day)).or_default();
+        if inputs.contains_key(input) {
+            return false;
+        }
+
+        inputs.insert(input.to_string(), PartAnswers::default());
+        true
+    }
+
+    pub fn check(&self, day: u32, input: &str, part: Part, answer: &str) -> Verdict {
+        match self.get(day, input, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::Part;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(7, "inputs/day07.txt", Part::One, String::from("3749"));
+
+        let serialized = toml::to_string(&answers).unwrap();
+        assert_eq!(
+            serialized,
+            "[day07.\"inputs/day07.txt\"]\npart1 = \"3749\"\n"
+        );
+
+        let answers: Answers = toml::from_str(&serialized).unwrap();
+        assert_eq!(
+            answers.check(7, "inputs/day07.txt", Part::One, "3749"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(7, "inputs/day07.txt", Part::One, "3748"),
+            Verdict::Fail {
+                expected: String::from("3749")
+            }
+        );
+        assert_eq!(
+            answers.check(7, "inputs/day07.txt", Part::Two, "11387"),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_add_placeholder() {
+        let mut answers = Answers::default();
+        assert!(answers.add_placeholder(14, "inputs/day14.txt"));
+
+        let serialized = toml::to_string(&answers).unwrap();
+        assert_eq!(serialized, "[day14.\"inputs/day14.txt\"]\n");
+
+        let mut answers: Answers = toml::from_str(&serialized).unwrap();
+        assert_eq!(answers.get(14, "inputs/day14.txt", Part::One), None);
+
+        answers.set(14, "inputs/day14.txt", Part::One, String::from("42"));
+        assert!(!answers.add_placeholder(14, "inputs/day14.txt"));
+        assert_eq!(answers.get(14, "inputs/day14.txt", Part::One), Some("42"));
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::solution::{DynSolution, Part};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    pub iterations: usize,
+    /// Untimed runs before the timed ones, to warm up caches and the allocator.
+    pub warmup: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Measurement {
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// A measurement along with the day and input file it was taken for.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub day: u32,
+    pub input: PathBuf,
+    pub measurement: Measurement,
+}
+
+fn sample<T>(options: &Options, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        black_box(f()?);
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        let result = f()?;
+        samples.push(start.elapsed());
+
+        black_box(result);
+    }
+
+    Ok(Stats::from_samples(&samples))
+}
+
+/// Times parsing and each of `parts` separately, `options.iterations` times each.
+pub fn run(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    options: &Options,
+) -> Result<Vec<Measurement>> {
+    let mut measurements = vec![Measurement {
+        phase: Phase::Parse,
+        stats: sample(options, || solution.parse(input))?,
+    }];
+
+    let parsed = solution.parse(input)?;
+    for part in parts {
+        measurements.push(Measurement {
+            phase: Phase::Solve(*part),
+            stats: sample(options, || solution.solve(parsed.as_ref(), *part))?,
+        });
+    }
+
+    Ok(measurements)
+}
+
+/// Formats a duration with a unit that keeps it readable, e.g. `12.34ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[derive(Serialize)]
+struct Record {
+    day: u32,
+    input: String,
+    phase: String,
+    iterations: usize,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+}
+
+impl Record {
+    fn new(entry: &Entry) -> Self {
+        let stats = &entry.measurement.stats;
+
+        Record {
+            day: entry.day,
+            input: entry.input.to_string_lossy().into_owned(),
+            phase: entry.measurement.phase.to_string(),
+            iterations: stats.iterations,
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        }
+    }
+}
+
+/// An aligned table for reading in a terminal.
+///
+/// Inputs are only listed when some day was benchmarked on more than one of them.
+pub fn to_table(entries: &[Entry]) -> String {
+    let days: HashSet<_> = entries.iter().map(|entry| entry.day).collect();
+    let inputs: HashSet<_> = entries
+        .iter()
+        .map(|entry| (entry.day, &entry.input))
+        .collect();
+    let show_inputs = inputs.len() > days.len();
+
+    let mut out = format!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Iterations", "Min", "Median", "Mean", "Stddev"
+    );
+    if show_inputs {
+        out.push_str("  Input");
+    }
+    out.push('\n');
+
+    for entry in entries {
+        let measurement = &entry.measurement;
+        let stats = &measurement.stats;
+
+        out.push_str(&format!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+            entry.day,
+            measurement.phase.to_string(),
+            stats.iterations,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.stddev)
+        ));
+        if show_inputs {
+            out.push_str(&format!("  {}", entry.input.display()));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// CSV with a header row named after the fields of `records`, quoting any field that needs it.
+pub(crate) fn records_to_csv<T: Serialize>(records: impl IntoIterator<Item = T>) -> String {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in records {
+        writer
+            .serialize(record)
+            .expect("records are always serializable");
+    }
+
+    let bytes = writer.into_inner().expect("writing to memory can't fail");
+    String::from_utf8(bytes).expect("records are UTF-8")
+}
+
+/// One line per measurement, with durations in nanoseconds.
+pub fn to_csv(entries: &[Entry]) -> String {
+    records_to_csv(entries.iter().map(Record::new))
+}
+
+/// A JSON array with one object per measurement, using the same fields as [`to_csv`].
+pub fn to_json(entries: &[Entry]) -> String {
+    let records: Vec<_> = entries.iter().map(Record::new).collect();
+
+    serde_json::to_string_pretty(&records).expect("records are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{to_csv, Entry, Measurement, Phase, Stats};
+    use crate::Part;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert!((stats.mean.as_secs_f64() - 0.0025).abs() < 1e-9);
+        assert!((stats.stddev.as_secs_f64() - 0.001118).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_csv_quotes_inputs() {
+        let entry = Entry {
+            day: 3,
+            input: "inputs/a,\"b\".txt".into(),
+            measurement: Measurement {
+                phase: Phase::Solve(Part::Two),
+                stats: Stats::from_samples(&[Duration::from_nanos(5)]),
+            },
+        };
+
+        assert_eq!(
+            to_csv(&[entry]),
+            "day,input,phase,iterations,min_ns,median_ns,mean_ns,stddev_ns\n\
+             3,\"inputs/a,\"\"b\"\".txt\",part2,1,5,5,5,0\n"
+        );
+    }
+}
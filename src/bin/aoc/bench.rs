@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+
+use aoc2024::bench::{self, Options};
+use aoc2024::cli::{self, exit_with};
+use aoc2024::history::{self, Record, Verdict};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+
+use crate::run;
+
+pub(crate) fn command() -> Command {
+    Command::new("bench")
+        .about("Benchmark days and keep the results in a history, keyed by git commit")
+        .args(run::job_args())
+        .arg(
+            Arg::new("iterations")
+                .long("iterations")
+                .short('n')
+                .help("Timed iterations of parsing and each part")
+                .default_value("20")
+                .value_parser(value_parser!(u32).range(1..)),
+        )
+        .arg(
+            Arg::new("warmup")
+                .long("warmup")
+                .value_name("N")
+                .help("Untimed iterations to run before benchmarking")
+                .default_value("3")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("history")
+                .long("history")
+                .help("Location of the history file")
+                .default_value("bench-history.jsonl")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("compare")
+                .long("compare")
+                .value_name("REF")
+                .help("Compare with the latest results for a commit, branch or tag in the history"),
+        )
+        .arg(
+            Arg::new("threshold")
+                .long("threshold")
+                .value_name("PERCENT")
+                .help("Smallest change in mean time that --compare reports")
+                .default_value("5")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("no-save")
+                .long("no-save")
+                .help("Don't add the results to the history")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Runs the subcommand and returns the process exit code.
+///
+/// This is the code of the first error, 1 if --compare found a regression, or 0.
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let parts = run::parts(args);
+    let jobs = run::jobs(command, args);
+    let options = Options {
+        iterations: *args.get_one::<u32>("iterations").unwrap() as usize,
+        warmup: *args.get_one::<u32>("warmup").unwrap() as usize,
+    };
+    let history_path = args.get_one::<PathBuf>("history").unwrap();
+
+    // everything that can fail before benchmarking is checked first
+    let commit = history::head().unwrap_or_else(|e| exit_with(&e));
+    let baseline = args
+        .get_one::<String>("compare")
+        .map(|reference| history::resolve(reference).unwrap_or_else(|e| exit_with(&e)));
+    let history = history::load(history_path).unwrap_or_else(|e| exit_with(&e));
+
+    let mut entries = Vec::new();
+    let mut code = 0;
+
+    for (day, solution, input_path) in &jobs {
+        match cli::bench_input(*day, *solution, input_path, &parts, &options) {
+            Ok(measured) => entries.extend(measured),
+            Err(e) => {
+                eprintln!("day {}: {}", day, e.diagnostic());
+                if code == 0 {
+                    code = e.exit_code();
+                }
+            }
+        }
+    }
+
+    let records: Vec<_> = entries
+        .iter()
+        .map(|entry| Record::new(&commit, entry))
+        .collect();
+
+    match &baseline {
+        Some(baseline) => {
+            let threshold = args.get_one::<f64>("threshold").unwrap() / 100.0;
+            let comparisons = history::compare(&history, baseline, &records, threshold);
+
+            println!(
+                "{} compared with {}",
+                commit,
+                args.get_one::<String>("compare").unwrap()
+            );
+            print!("{}", history::to_trend_table(&comparisons));
+
+            let regressions = comparisons
+                .iter()
+                .filter(|comparison| comparison.verdict == Verdict::Regression)
+                .count();
+            if regressions > 0 && code == 0 {
+                code = 1;
+            }
+        }
+        None => print!("{}", bench::to_table(&entries)),
+    }
+
+    if !args.get_flag("no-save") && !records.is_empty() {
+        if let Err(e) = history::append(history_path, &records) {
+            exit_with(&e);
+        }
+
+        println!(
+            "saved {} measurements for {} to {}",
+            records.len(),
+            commit,
+            history_path.display()
+        );
+    }
+
+    code
+}
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc2024::cli::{self, exit_with};
+use aoc2024::client::{self, Client, RateLimiter};
+use aoc2024::Error;
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+
+/// Arguments for talking to the website, shared with `submit`.
+pub(crate) fn site_args() -> [Arg; 2] {
+    [
+        Arg::new("input-dir")
+            .long("input-dir")
+            .help(format!(
+                "Directory holding the cached inputs [default: ${}, or inputs]",
+                cli::INPUT_DIR_VAR
+            ))
+            .value_parser(value_parser!(PathBuf)),
+        Arg::new("config")
+            .long("config")
+            .help(format!(
+                "TOML file with the session cookie as `session`, used if ${} isn't set",
+                client::SESSION_VAR
+            ))
+            .default_value("aoc.toml")
+            .value_parser(value_parser!(PathBuf)),
+    ]
+}
+
+pub(crate) fn input_dir(args: &ArgMatches) -> PathBuf {
+    args.get_one::<PathBuf>("input-dir")
+        .cloned()
+        .unwrap_or_else(cli::input_dir)
+}
+
+/// A client for the site, exiting with a usage error if there is no session cookie.
+pub(crate) fn client(command: &mut Command, args: &ArgMatches) -> Client {
+    let config = args.get_one::<PathBuf>("config").unwrap();
+
+    let session = match client::session(config) {
+        Ok(Some(session)) => session,
+        Ok(None) => command
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "no session cookie; set ${} or `session` in {}",
+                    client::SESSION_VAR,
+                    config.display()
+                ),
+            )
+            .exit(),
+        Err(e) => exit_with(&e),
+    };
+
+    // the stamp lives next to the inputs so that every run shares it
+    let limiter = RateLimiter::new(input_dir(args).join(".last-request"), client::MIN_INTERVAL);
+
+    Client::new(client::base_url(), session, limiter)
+}
+
+pub(crate) fn command() -> Command {
+    Command::new("fetch")
+        .about("Download the input for a day into the input directory")
+        .arg(
+            Arg::new("day")
+                .required(true)
+                .value_parser(value_parser!(u32).range(1..=25)),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .help("Download the input again even if it is already cached")
+                .action(ArgAction::SetTrue),
+        )
+        .args(site_args())
+}
+
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let day = *args.get_one::<u32>("day").unwrap();
+    let path = input_dir(args).join(format!("day{:02}.txt", day));
+
+    if path.exists() && !args.get_flag("force") {
+        eprintln!("{} is already downloaded", path.display());
+        return 0;
+    }
+
+    let client = client(command, args);
+    let input = match client.fetch_input(day) {
+        Ok(input) => input,
+        Err(e) => exit_with(&e),
+    };
+
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, input));
+    if let Err(e) = written {
+        exit_with(&Error::io(&path, e));
+    }
+
+    eprintln!("saved {}", path.display());
+    0
+}
@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc2024::cli::exit_with;
+use aoc2024::{days, Error};
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub(crate) fn command() -> Command {
+    Command::new("gen")
+        .about("Generate a random input for a day")
+        .arg(
+            Arg::new("day")
+                .required(true)
+                .value_parser(value_parser!(u32).range(1..=25)),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .help("Seed for the generator; the same seed always gives the same input")
+                .default_value("0")
+                .value_parser(value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("scale")
+                .long("scale")
+                .help("Size of the input, where 1 is about the size of a real input")
+                .default_value("1")
+                .value_parser(value_parser!(u32).range(1..)),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .help("Write the input to a file instead of stdout")
+                .value_parser(value_parser!(PathBuf)),
+        )
+}
+
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let day = *args.get_one::<u32>("day").unwrap();
+    let seed = *args.get_one::<u64>("seed").unwrap();
+    let scale = *args.get_one::<u32>("scale").unwrap() as usize;
+
+    let Some(input) = days::generate(day, seed, scale) else {
+        command
+            .error(
+                ErrorKind::InvalidValue,
+                format!("no generator for day {}", day),
+            )
+            .exit();
+    };
+
+    match args.get_one::<PathBuf>("output") {
+        Some(path) => {
+            if let Err(e) = fs::write(path, input) {
+                exit_with(&Error::io(path, e));
+            }
+        }
+        None => print!("{}", input),
+    }
+
+    0
+}
@@ -0,0 +1,58 @@
+use std::process;
+
+use aoc2024::cli;
+use aoc2024::mem::CountingAllocator;
+use clap::command;
+
+mod bench;
+mod fetch;
+mod gen;
+mod new;
+mod run;
+mod serve;
+mod submit;
+mod table;
+mod validate;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    let mut command = command!()
+        .subcommand_required(true)
+        .arg(cli::verbose_arg().global(true))
+        .arg(cli::threads_arg().global(true))
+        .subcommand(run::command())
+        .subcommand(gen::command())
+        .subcommand(fetch::command())
+        .subcommand(submit::command())
+        .subcommand(new::command())
+        .subcommand(validate::command())
+        .subcommand(serve::command())
+        .subcommand(bench::command());
+    let args = command.get_matches_mut();
+    cli::init_logging(&args);
+    cli::init_threads(&args);
+
+    let code = match args.subcommand() {
+        Some(("run", sub_args)) => run::exec(command.find_subcommand_mut("run").unwrap(), sub_args),
+        Some(("gen", sub_args)) => gen::exec(command.find_subcommand_mut("gen").unwrap(), sub_args),
+        Some(("fetch", sub_args)) => {
+            fetch::exec(command.find_subcommand_mut("fetch").unwrap(), sub_args)
+        }
+        Some(("submit", sub_args)) => {
+            submit::exec(command.find_subcommand_mut("submit").unwrap(), sub_args)
+        }
+        Some(("new", sub_args)) => new::exec(command.find_subcommand_mut("new").unwrap(), sub_args),
+        Some(("validate", sub_args)) => {
+            validate::exec(command.find_subcommand_mut("validate").unwrap(), sub_args)
+        }
+        Some(("serve", sub_args)) => serve::exec(sub_args),
+        Some(("bench", sub_args)) => {
+            bench::exec(command.find_subcommand_mut("bench").unwrap(), sub_args)
+        }
+        _ => unreachable!("subcommand is required"),
+    };
+
+    process::exit(code);
+}
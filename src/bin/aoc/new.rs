@@ -0,0 +1,256 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc2024::answers::Answers;
+use aoc2024::cli::{self, exit_with};
+use aoc2024::{Error, Result};
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+const MAIN_TEMPLATE: &str = include_str!("templates/main.rs.in");
+const MOD_TEMPLATE: &str = include_str!("templates/mod.rs.in");
+const PARSER_TEMPLATE: &str = include_str!("templates/parser.rs.in");
+const GENERATOR_TEMPLATE: &str = include_str!("templates/generator.rs.in");
+const VALIDATOR_TEMPLATE: &str = include_str!("templates/validator.rs.in");
+
+pub(crate) fn command() -> Command {
+    Command::new("new")
+        .about("Create the module, binary and example for a new day from a template")
+        .arg(
+            Arg::new("day")
+                .required(true)
+                .value_parser(value_parser!(u32).range(1..=25)),
+        )
+        .arg(
+            Arg::new("root")
+                .long("root")
+                .help("Root of the repository to add the day to")
+                .default_value(".")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("answers")
+                .long("answers")
+                .help("Location of the answers file to add a placeholder to")
+                .default_value("answers.toml")
+                .value_parser(value_parser!(PathBuf)),
+        )
+}
+
+/// Inserts `line` into the first run of lines matching `is_member`, keeping the run ordered by
+/// `key`.
+fn insert_line<K: Ord>(
+    source: &str,
+    line: &str,
+    is_member: impl Fn(&str) -> bool,
+    key: impl Fn(&str) -> K,
+) -> Result<String> {
+    let lines: Vec<&str> = source.lines().collect();
+
+    let Some(start) = lines.iter().position(|l| is_member(l)) else {
+        return Err(Error::parse(format!(
+            "could not find where to add `{}`",
+            line.trim()
+        )));
+    };
+    let end = start + lines[start..].iter().take_while(|l| is_member(l)).count();
+
+    let new_key = key(line);
+    let mut index = start;
+    while index < end && key(lines[index]) < new_key {
+        index += 1;
+    }
+
+    if index < end && key(lines[index]) == new_key {
+        return Err(Error::parse(format!(
+            "`{}` is already there",
+            lines[index].trim()
+        )));
+    }
+
+    let mut result: Vec<&str> = lines[..index].to_vec();
+    result.push(line);
+    result.extend(&lines[index..]);
+
+    Ok(result.join("\n") + "\n")
+}
+
+/// The `dayN` module named in a line like `pub use day7::Day7;`.
+fn module_name(line: &str) -> &str {
+    line.split(|c: char| !c.is_ascii_alphanumeric())
+        .find(|word| word.starts_with("day"))
+        .unwrap_or("")
+}
+
+/// The number a match arm like `        7 => Some(&Day7),` matches on.
+fn arm_day(line: &str) -> u32 {
+    line.trim()
+        .split(' ')
+        .next()
+        .and_then(|day| day.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Adds `day` to the `DAYS` table, keeping it in order and its length up to date.
+fn add_to_days(source: &str, day: u32) -> Result<String> {
+    const DECLARATION: &str = "pub const DAYS: [u32; ";
+    let unreadable = || Error::parse("could not read the DAYS table");
+
+    let start = source
+        .find(DECLARATION)
+        .ok_or_else(|| Error::parse("could not find the DAYS table"))?;
+    let end = start + source[start..].find("];").ok_or_else(unreadable)? + "];".len();
+
+    let (_, list) = source[start..end]
+        .split_once("= [")
+        .ok_or_else(unreadable)?;
+    let mut days = list
+        .trim_end_matches("];")
+        .split(',')
+        .map(str::trim)
+        .filter(|day| !day.is_empty())
+        .map(|day| day.parse::<u32>().map_err(|_| unreadable()))
+        .collect::<Result<Vec<_>>>()?;
+
+    if days.contains(&day) {
+        return Err(Error::parse(format!("day {} is already in DAYS", day)));
+    }
+    days.push(day);
+    days.sort_unstable();
+
+    let list: Vec<_> = days.iter().map(u32::to_string).collect();
+    Ok(format!(
+        "{}{}{}] = [{}];{}",
+        &source[..start],
+        DECLARATION,
+        days.len(),
+        list.join(", "),
+        &source[end..]
+    ))
+}
+
+/// Adds `day` to the module declarations, re-exports, `DAYS`, `get`, `generate` and `validate`
+/// in `src/days/mod.rs`.
+fn register(source: &str, day: u32) -> Result<String> {
+    let source = insert_line(
+        source,
+        &format!("pub mod day{};", day),
+        |l: &str| l.starts_with("pub mod day"),
+        |l: &str| module_name(l).to_string(),
+    )?;
+    let source = insert_line(
+        &source,
+        &format!("pub use day{0}::Day{0};", day),
+        |l: &str| l.starts_with("pub use day"),
+        |l: &str| module_name(l).to_string(),
+    )?;
+    let source = add_to_days(&source, day)?;
+    let source = insert_line(
+        &source,
+        &format!("        {0} => Some(&Day{0}),", day),
+        |l: &str| l.contains(" => Some(&Day"),
+        arm_day,
+    )?;
+
+    let source = insert_line(
+        &source,
+        &format!("        {0} => day{0}::generator::generate,", day),
+        |l: &str| l.contains("::generator::generate,"),
+        arm_day,
+    )?;
+
+    insert_line(
+        &source,
+        &format!("        {0} => day{0}::validator::validate,", day),
+        |l: &str| l.contains("::validator::validate,"),
+        arm_day,
+    )
+}
+
+fn fill(template: &str, day: u32) -> String {
+    template.replace("__DAY__", &day.to_string())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+    }
+
+    fs::write(path, contents).map_err(|e| Error::io(path, e))?;
+    println!("created {}", path.display());
+    Ok(())
+}
+
+fn create(root: &Path, day: u32, answers_path: &Path) -> Result<()> {
+    let days_path = root.join("src/days/mod.rs");
+    let days_source = fs::read_to_string(&days_path).map_err(|e| Error::io(&days_path, e))?;
+
+    // registering is the step most likely to fail, so it happens before anything is written
+    let registered = register(&days_source, day).map_err(|e| match e {
+        Error::Parse { message, .. } => {
+            Error::parse(format!("{}: {}", days_path.display(), message))
+        }
+        e => e,
+    })?;
+
+    let module = root.join(format!("src/days/day{}", day));
+    write(&module.join("mod.rs"), &fill(MOD_TEMPLATE, day))?;
+    write(&module.join("parser.rs"), &fill(PARSER_TEMPLATE, day))?;
+    write(&module.join("generator.rs"), &fill(GENERATOR_TEMPLATE, day))?;
+    write(&module.join("validator.rs"), &fill(VALIDATOR_TEMPLATE, day))?;
+    write(&module.join("example.txt"), "")?;
+    write(
+        &root.join(format!("src/bin/day{}/main.rs", day)),
+        &fill(MAIN_TEMPLATE, day),
+    )?;
+
+    fs::write(&days_path, registered).map_err(|e| Error::io(&days_path, e))?;
+    println!("registered day {} in {}", day, days_path.display());
+
+    let mut answers = Answers::load(answers_path)?;
+    let input = cli::default_input(day);
+    if answers.add_placeholder(day, &input.to_string_lossy()) {
+        answers.save(answers_path)?;
+        println!(
+            "added a placeholder for {} to {}",
+            input.display(),
+            answers_path.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs the subcommand and returns the process exit code.
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let day = *args.get_one::<u32>("day").unwrap();
+    let root = args.get_one::<PathBuf>("root").unwrap();
+    let answers_path = args.get_one::<PathBuf>("answers").unwrap();
+
+    if !root.join("src/days/mod.rs").is_file() {
+        command
+            .error(
+                ErrorKind::ValueValidation,
+                format!(
+                    "{} is not the repository root, as it has no src/days/mod.rs",
+                    root.display()
+                ),
+            )
+            .exit();
+    }
+
+    if root.join(format!("src/days/day{}", day)).exists() {
+        command
+            .error(
+                ErrorKind::ValueValidation,
+                format!("day {} already exists", day),
+            )
+            .exit();
+    }
+
+    if let Err(e) = create(root, day, answers_path) {
+        exit_with(&e);
+    }
+
+    0
+}
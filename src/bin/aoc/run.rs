@@ -0,0 +1,367 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use aoc2024::answers::{Answers, Verdict};
+use aoc2024::budget::Budget;
+use aoc2024::cli::{self, exit_with, Format, Mode};
+use aoc2024::report::{self, Report};
+use aoc2024::{days, parallel, DynSolution, Error, Part};
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use cpu_time::ProcessTime;
+use serde_json::Value;
+
+use crate::table;
+
+pub(crate) struct Outcome {
+    pub report: Report,
+    pub verdict: Option<Verdict>,
+    pub recorded: bool,
+}
+
+impl Outcome {
+    fn to_json(&self) -> Value {
+        let mut value = self.report.to_json();
+
+        if let Some(verdict) = &self.verdict {
+            let object = value.as_object_mut().expect("reports are objects");
+            let (check, expected) = match verdict {
+                Verdict::Pass => ("pass", None),
+                Verdict::Fail { expected } => ("fail", Some(expected.clone())),
+                Verdict::Unknown => ("unknown", None),
+            };
+
+            object.insert("check".into(), check.into());
+            object.insert("expected".into(), expected.into());
+            object.insert("recorded".into(), self.recorded.into());
+        }
+
+        value
+    }
+}
+
+/// A day to run, with its solution and an input.
+pub(crate) type Job = (u32, &'static dyn DynSolution, PathBuf);
+
+/// The arguments choosing the days, inputs and parts to run.
+pub(crate) fn job_args() -> [Arg; 6] {
+    [
+        Arg::new("day")
+            .required_unless_present("all")
+            .value_parser(value_parser!(u32).range(1..=25)),
+        cli::inputs_arg().conflicts_with("all"),
+        Arg::new("example")
+            .long("example")
+            .help("Run on the day's examples, src/days/dayN/example*.txt")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["input", "all"]),
+        Arg::new("part")
+            .long("part")
+            .short('p')
+            .help("Only run the given part")
+            .value_parser(value_parser!(u8).range(1..=2)),
+        Arg::new("all")
+            .long("all")
+            .help("Run every day from <input-dir>/dayNN.txt, with a wall-clock and CPU summary")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("day"),
+        Arg::new("input-dir")
+            .long("input-dir")
+            .help(format!(
+                "Directory holding the inputs for --all [default: ${}, or inputs]",
+                cli::INPUT_DIR_VAR
+            ))
+            .requires("all")
+            .value_parser(value_parser!(PathBuf)),
+    ]
+}
+
+pub(crate) fn parts(args: &ArgMatches) -> Vec<Part> {
+    match args.get_one::<u8>("part") {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// The example inputs kept with the source of `day`, in order of name.
+fn examples(day: u32) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(format!("src/days/day{}", day)) else {
+        return Vec::new();
+    };
+
+    let mut examples: Vec<_> = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("example") && name.ends_with(".txt"))
+        })
+        .collect();
+    examples.sort();
+
+    examples
+}
+
+/// Every day with its inputs for `--all`, or else the day and inputs given.
+pub(crate) fn jobs(command: &mut Command, args: &ArgMatches) -> Vec<Job> {
+    if args.get_flag("all") {
+        let input_dir = args
+            .get_one::<PathBuf>("input-dir")
+            .cloned()
+            .unwrap_or_else(cli::input_dir);
+
+        days::DAYS
+            .iter()
+            .map(|day| {
+                let solution = days::get(*day).expect("listed days have solutions");
+                (*day, solution, input_dir.join(format!("day{:02}.txt", day)))
+            })
+            .collect()
+    } else {
+        let day = *args.get_one::<u32>("day").unwrap();
+
+        let Some(solution) = days::get(day) else {
+            command
+                .error(
+                    ErrorKind::InvalidValue,
+                    format!("no solution for day {}", day),
+                )
+                .exit();
+        };
+
+        let inputs = if args.get_flag("example") {
+            let examples = examples(day);
+            if examples.is_empty() {
+                command
+                    .error(
+                        ErrorKind::InvalidValue,
+                        format!("day {} has no examples in src/days/day{}", day, day),
+                    )
+                    .exit();
+            }
+
+            examples
+        } else {
+            cli::inputs(args, day)
+        };
+
+        inputs
+            .into_iter()
+            .map(|input_path| (day, solution, input_path))
+            .collect()
+    }
+}
+
+pub(crate) fn command() -> Command {
+    let [day, inputs, example, part, all, input_dir] = job_args();
+
+    Command::new("run")
+        .about("Run the solution for one day, or for every day")
+        .args([day, inputs, example, part])
+        .arg(all.help("Run every day concurrently, reading inputs from <input-dir>/dayNN.txt"))
+        .arg(input_dir)
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .help("Compare answers with the answers file")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("record")
+                .long("record")
+                .help("Save answers that are not in the answers file yet (implies --check)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("answers")
+                .long("answers")
+                .help("Location of the answers file")
+                .default_value("answers.toml")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .args(cli::mode_args())
+        .args(cli::budget_args().map(|arg| arg.conflicts_with_all(["bench", "mem"])))
+        .arg(cli::render_arg().conflicts_with("all"))
+}
+
+fn run_day(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    parts: &[Part],
+    budget: &Budget,
+) -> Vec<Outcome> {
+    report::solve(day, solution, input_path, parts, budget)
+        .into_iter()
+        .map(|report| Outcome {
+            report,
+            verdict: None,
+            recorded: false,
+        })
+        .collect()
+}
+
+fn check_answers(outcomes: &mut [Outcome], answers_path: &Path, record: bool) -> Result<(), Error> {
+    let mut answers = Answers::load(answers_path)?;
+    let mut changed = false;
+
+    for outcome in outcomes.iter_mut() {
+        let report = &outcome.report;
+        let Ok(answer) = &report.answer else {
+            continue;
+        };
+
+        let input = report.input.to_string_lossy();
+        let verdict = answers.check(report.day, &input, report.part, answer);
+
+        if record && verdict == Verdict::Unknown {
+            answers.set(report.day, &input, report.part, answer.clone());
+            outcome.recorded = true;
+            changed = true;
+        }
+
+        outcome.verdict = Some(verdict);
+    }
+
+    if changed {
+        answers.save(answers_path)?;
+    }
+
+    Ok(())
+}
+
+/// Benchmarks or measures the heap usage of every job, skipping days whose input can't be read
+/// or solved.
+fn run_measurements(jobs: &[Job], parts: &[Part], mode: Mode, format: Format) -> i32 {
+    let mut bench_entries = Vec::new();
+    let mut mem_entries = Vec::new();
+    let mut code = 0;
+
+    for (day, solution, input_path) in jobs {
+        let result = match mode {
+            Mode::Bench(options) => cli::bench_input(*day, *solution, input_path, parts, &options)
+                .map(|measured| bench_entries.extend(measured)),
+            Mode::Mem => cli::mem_input(*day, *solution, input_path, parts)
+                .map(|measured| mem_entries.extend(measured)),
+            Mode::Answers => unreachable!("answers are not measurements"),
+        };
+
+        if let Err(e) = result {
+            eprintln!("day {}: {}", day, e.diagnostic());
+            if code == 0 {
+                code = e.exit_code();
+            }
+        }
+    }
+
+    match mode {
+        Mode::Bench(_) => cli::print_bench(&bench_entries, format),
+        Mode::Mem => cli::print_mem(&mem_entries, format),
+        Mode::Answers => {}
+    }
+
+    code
+}
+
+/// Runs the subcommand and returns the process exit code.
+///
+/// This is the code of the first error, 1 if any answer failed its check, or 0.
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let parts = parts(args);
+    let all = args.get_flag("all");
+    let jobs = jobs(command, args);
+
+    if let (false, Some((day, solution, _))) = (all, jobs.first()) {
+        let inputs: Vec<_> = jobs
+            .iter()
+            .map(|(_, _, input_path)| input_path.clone())
+            .collect();
+        if let Err(e) = cli::render(command, args, *day, *solution, &inputs) {
+            exit_with(&e);
+        }
+    }
+
+    let format = cli::format(args);
+
+    let mode = cli::mode(args);
+    if !matches!(mode, Mode::Answers) {
+        return run_measurements(&jobs, &parts, mode, format);
+    }
+
+    if format == Format::Csv {
+        command
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--format csv is only supported with --bench or --mem",
+            )
+            .exit();
+    }
+
+    let budget = cli::budget(args);
+    let run_job =
+        |(day, solution, input_path): &Job| run_day(*day, *solution, input_path, &parts, &budget);
+
+    // jobs run concurrently with the parallel feature, keeping their order. Heap usage is only
+    // counted for the whole process, so jobs with a memory budget run one at a time to be told
+    // apart
+    let start = Instant::now();
+    let cpu_start = ProcessTime::now();
+    let mut outcomes: Vec<Outcome> = if budget.max_mem.is_some() {
+        jobs.iter().flat_map(run_job).collect()
+    } else {
+        parallel::map(&jobs, run_job)
+            .into_iter()
+            .flatten()
+            .collect()
+    };
+    let wall_time = start.elapsed();
+    let cpu_time = cpu_start.elapsed();
+
+    let record = args.get_flag("record");
+    if record || args.get_flag("check") {
+        let answers_path = args.get_one::<PathBuf>("answers").unwrap();
+
+        if let Err(e) = check_answers(&mut outcomes, answers_path, record) {
+            exit_with(&e);
+        }
+    }
+
+    if format == Format::Json {
+        for outcome in &outcomes {
+            println!("{}", outcome.to_json());
+        }
+    } else {
+        table::print(&outcomes, all);
+
+        if all {
+            table::print_summary(&outcomes, wall_time, cpu_time);
+        }
+    }
+
+    // a parse error is reported once per part, so only show the first of each
+    let mut errors: Vec<&Error> = Vec::new();
+    for outcome in &outcomes {
+        if let Err(e) = &outcome.report.answer {
+            if errors
+                .last()
+                .is_none_or(|last| last.to_string() != e.to_string())
+            {
+                eprintln!("day {}: {}", outcome.report.day, e.diagnostic());
+                errors.push(e);
+            }
+        }
+    }
+
+    if let Some(e) = errors.first() {
+        e.exit_code()
+    } else if outcomes
+        .iter()
+        .any(|outcome| matches!(outcome.verdict, Some(Verdict::Fail { .. })))
+    {
+        1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,49 @@
+use aoc2024::budget;
+use aoc2024::cli::{self, exit_with};
+use aoc2024::serve::Server;
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub(crate) fn command() -> Command {
+    Command::new("serve")
+        .about("Serve the solutions as a JSON API: POST an input to /day/{n}/part/{p}")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .help("Port to listen on, where 0 picks a free one")
+                .default_value("8080")
+                .value_parser(value_parser!(u16)),
+        )
+        .arg(
+            Arg::new("host")
+                .long("host")
+                .help("Address to listen on")
+                .default_value("127.0.0.1"),
+        )
+        .arg(
+            Arg::new("max-body")
+                .long("max-body")
+                .help("Largest input to accept, such as 512K or 16M")
+                .default_value("16M")
+                .value_parser(budget::parse_size),
+        )
+        .args(cli::budget_args())
+}
+
+/// Runs the subcommand, which only returns if the server can't be started.
+pub(crate) fn exec(args: &ArgMatches) -> i32 {
+    let port = *args.get_one::<u16>("port").unwrap();
+    let host = args.get_one::<String>("host").unwrap();
+    let max_body = *args.get_one::<usize>("max-body").unwrap();
+
+    let server = match Server::bind(&format!("{}:{}", host, port)) {
+        Ok(server) => server
+            .with_budget(cli::budget(args))
+            .with_max_body(max_body as u64),
+        Err(e) => exit_with(&e),
+    };
+
+    println!("listening on http://{}", server.addr());
+    server.run();
+
+    0
+}
@@ -0,0 +1,72 @@
+use aoc2024::cli::exit_with;
+use aoc2024::client::Outcome;
+use aoc2024::input::read_input;
+use aoc2024::{days, Part};
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+use crate::fetch;
+
+pub(crate) fn command() -> Command {
+    Command::new("submit")
+        .about("Submit an answer for one part of a day")
+        .arg(
+            Arg::new("day")
+                .required(true)
+                .value_parser(value_parser!(u32).range(1..=25)),
+        )
+        .arg(
+            Arg::new("part")
+                .required(true)
+                .value_parser(value_parser!(u8).range(1..=2)),
+        )
+        .arg(Arg::new("answer").help("The answer to submit [default: solve the cached input]"))
+        .args(fetch::site_args())
+}
+
+/// Runs the subcommand and returns the process exit code, which is 1 unless the answer was
+/// correct.
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let day = *args.get_one::<u32>("day").unwrap();
+    let part = match args.get_one::<u8>("part") {
+        Some(1) => Part::One,
+        _ => Part::Two,
+    };
+
+    let answer = match args.get_one::<String>("answer") {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let Some(solution) = days::get(day) else {
+                command
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        format!("no solution for day {}, so an answer must be given", day),
+                    )
+                    .exit();
+            };
+
+            let path = fetch::input_dir(args).join(format!("day{:02}.txt", day));
+            let answer = read_input(&path)
+                .and_then(|input| solution.parse(&input))
+                .and_then(|input| solution.solve(input.as_ref(), part));
+
+            match answer {
+                Ok(answer) => answer,
+                Err(e) => exit_with(&e),
+            }
+        }
+    };
+
+    let client = fetch::client(command, args);
+    match client.submit(day, part, &answer) {
+        Ok(outcome) => {
+            println!("day {} part {}: {}: {}", day, part, answer, outcome);
+            if outcome == Outcome::Correct {
+                0
+            } else {
+                1
+            }
+        }
+        Err(e) => exit_with(&e),
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use aoc2024::bench::format_duration;
+
+use crate::run::Outcome;
+
+fn print_row(cells: &[String], widths: &[usize]) {
+    let line: Vec<_> = cells
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(index, (cell, width))| {
+            // day and part are right aligned, everything after is left aligned
+            if index < 2 {
+                format!("{:>width$}", cell, width = width)
+            } else {
+                format!("{:<width$}", cell, width = width)
+            }
+        })
+        .collect();
+
+    println!("{}", line.join("  ").trim_end());
+}
+
+/// The parse time of each day and input, which every part's report repeats, paired with whether
+/// the outcome is the first one for its input.
+fn parse_times(outcomes: &[Outcome]) -> impl Iterator<Item = (Option<Duration>, bool)> + '_ {
+    outcomes.iter().enumerate().map(|(index, outcome)| {
+        let report = &outcome.report;
+        let first = index == 0 || {
+            let previous = &outcomes[index - 1].report;
+            (previous.day, &previous.input) != (report.day, &report.input)
+        };
+
+        (report.parse_time, first)
+    })
+}
+
+pub(crate) fn print(outcomes: &[Outcome], show_times: bool) {
+    let show_verdicts = outcomes.iter().any(|outcome| outcome.verdict.is_some());
+
+    // inputs only need listing when some day was run on more than one of them
+    let days: HashSet<_> = outcomes.iter().map(|outcome| outcome.report.day).collect();
+    let inputs: HashSet<_> = outcomes
+        .iter()
+        .map(|outcome| (outcome.report.day, &outcome.report.input))
+        .collect();
+    let show_inputs = inputs.len() > days.len();
+
+    let mut headers = vec!["Day", "Part", "Answer"];
+    if show_inputs {
+        headers.push("Input");
+    }
+    if show_verdicts {
+        headers.push("Check");
+    }
+    if show_times {
+        headers.extend(["Parse", "Solve"]);
+    }
+
+    let rows: Vec<Vec<String>> = outcomes
+        .iter()
+        .zip(parse_times(outcomes))
+        .map(|(outcome, (parse_time, first))| {
+            let report = &outcome.report;
+            let answer = match &report.answer {
+                Ok(answer) => answer.clone(),
+                Err(e) => format!("error: {}", e),
+            };
+
+            let mut row = vec![report.day.to_string(), report.part.to_string(), answer];
+
+            if show_inputs {
+                row.push(report.input.display().to_string());
+            }
+
+            if show_verdicts {
+                let verdict = match &outcome.verdict {
+                    Some(verdict) if outcome.recorded => format!("{} (recorded)", verdict),
+                    Some(verdict) => verdict.to_string(),
+                    None => String::new(),
+                };
+
+                row.push(verdict);
+            }
+
+            if show_times {
+                let time = |time: Option<Duration>| time.map(format_duration).unwrap_or_default();
+
+                row.push(if first {
+                    time(parse_time)
+                } else {
+                    String::new()
+                });
+                row.push(time(report.solve_time));
+            }
+
+            row
+        })
+        .collect();
+
+    let mut widths: Vec<_> = headers.iter().map(|header| header.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let headers: Vec<_> = headers.into_iter().map(String::from).collect();
+    let separators: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+    print_row(&headers, &widths);
+    print_row(&separators, &widths);
+
+    for row in &rows {
+        print_row(row, &widths);
+    }
+}
+
+/// Prints how long the run took, against the CPU time the process used and the wall-clock time of
+/// each day added up.
+///
+/// CPU time is measured for the whole process rather than per day, as a day's parallel work runs
+/// on threads shared with the others. Its ratio to the run's wall-clock time is how many cores
+/// were busy on average, and the ratio of the days' total is how many were running at once.
+pub(crate) fn print_summary(outcomes: &[Outcome], wall_time: Duration, cpu_time: Duration) {
+    let parse_total: Duration = parse_times(outcomes)
+        .filter_map(|(parse_time, first)| parse_time.filter(|_| first))
+        .sum();
+    let solve_total: Duration = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.report.solve_time)
+        .sum();
+    let total = parse_total + solve_total;
+
+    let days: HashSet<_> = outcomes.iter().map(|outcome| outcome.report.day).collect();
+    let per_wall_second =
+        |time: Duration| time.as_secs_f64() / wall_time.as_secs_f64().max(f64::EPSILON);
+
+    println!();
+    println!(
+        "{} days in {} wall-clock, {} CPU, {:.2} cores busy",
+        days.len(),
+        format_duration(wall_time),
+        format_duration(cpu_time),
+        per_wall_second(cpu_time)
+    );
+    println!(
+        "{} total wall ({} parsing, {} solving), {:.2} running at once",
+        format_duration(total),
+        format_duration(parse_total),
+        format_duration(solve_total),
+        per_wall_second(total)
+    );
+}
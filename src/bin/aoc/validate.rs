@@ -0,0 +1,67 @@
+use aoc2024::input::read_input;
+use aoc2024::{cli, days};
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub(crate) fn command() -> Command {
+    Command::new("validate")
+        .about("Check that inputs have the structure a day's puzzle describes")
+        .arg(
+            Arg::new("day")
+                .required(true)
+                .value_parser(value_parser!(u32).range(1..=25)),
+        )
+        .arg(cli::inputs_arg())
+}
+
+/// Runs the subcommand and returns the process exit code.
+///
+/// This is the code of the first input that could not be read, 1 if any input is invalid, or 0.
+pub(crate) fn exec(command: &mut Command, args: &ArgMatches) -> i32 {
+    let day = *args.get_one::<u32>("day").unwrap();
+
+    let mut code = 0;
+
+    for input_path in cli::inputs(args, day) {
+        let input = match read_input(&input_path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}", e.diagnostic());
+                if code == 0 {
+                    code = e.exit_code();
+                }
+                continue;
+            }
+        };
+
+        let Some(violations) = days::validate(day, &input) else {
+            command
+                .error(
+                    ErrorKind::InvalidValue,
+                    format!("no validator for day {}", day),
+                )
+                .exit();
+        };
+
+        if violations.is_empty() {
+            println!("{}: valid", input_path.display());
+            continue;
+        }
+
+        println!(
+            "{}: {} problem{}",
+            input_path.display(),
+            violations.len(),
+            if violations.len() == 1 { "" } else { "s" }
+        );
+        for violation in &violations {
+            println!("{}", violation.diagnostic());
+        }
+
+        if code == 0 {
+            code = 1;
+        }
+    }
+
+    code
+}
@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::mem;
+
+/// Limits on how long a phase may run and how much heap it may use.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Budget {
+    pub timeout: Option<Duration>,
+    /// Bytes the heap may grow by.
+    pub max_mem: Option<usize>,
+}
+
+/// Lets a running solver check whether it is still within its budget.
+///
+/// Solvers call [`current`] when they start, and [`Token::check`] from their long-running loops,
+/// returning the error to stop cleanly. The token is passed explicitly to work run on other
+/// threads, which don't share the current one.
+#[derive(Clone, Debug, Default)]
+pub struct Token {
+    limits: Option<Arc<Limits>>,
+}
+
+#[derive(Debug)]
+struct Limits {
+    budget: Budget,
+    start: Instant,
+    heap_start: isize,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Token> = RefCell::new(Token::default());
+}
+
+/// Puts back the token that was current before a [`Budget::run`], even if it panics.
+struct Restore(Token);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let token = std::mem::take(&mut self.0);
+        CURRENT.with(|current| *current.borrow_mut() = token);
+    }
+}
+
+impl Budget {
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.max_mem.is_none()
+    }
+
+    /// Runs `f` with this budget as the current one.
+    ///
+    /// The budget is checked again once `f` returns, so that a solver that never checks it is
+    /// still reported, if only after it finishes.
+    pub fn run<T>(&self, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        if self.is_unlimited() {
+            return f();
+        }
+
+        if self.max_mem.is_some() {
+            mem::track_heap();
+        }
+
+        let token = Token {
+            limits: Some(Arc::new(Limits {
+                budget: *self,
+                start: Instant::now(),
+                heap_start: mem::heap_in_use(),
+            })),
+        };
+        let restore = Restore(CURRENT.with(|current| current.replace(token.clone())));
+
+        let result = f();
+        drop(restore);
+
+        token.check()?;
+        result
+    }
+}
+
+impl Token {
+    /// A token that is never out of budget.
+    pub fn unlimited() -> Self {
+        Token::default()
+    }
+
+    /// Fails with [`Error::BudgetExceeded`] once the time or memory has run out.
+    pub fn check(&self) -> Result<()> {
+        let Some(limits) = &self.limits else {
+            return Ok(());
+        };
+
+        if let Some(timeout) = limits.budget.timeout {
+            if limits.start.elapsed() > timeout {
+                return Err(Error::BudgetExceeded(format!(
+                    "ran for longer than {:?}",
+                    timeout
+                )));
+            }
+        }
+
+        if let Some(max_mem) = limits.budget.max_mem {
+            if mem::heap_in_use() - limits.heap_start > max_mem as isize {
+                return Err(Error::BudgetExceeded(format!(
+                    "used more than {} of heap",
+                    mem::format_bytes(max_mem)
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The token for the budget running on this thread, which is unlimited outside of
+/// [`Budget::run`].
+pub fn current() -> Token {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Parses a `--timeout` value: a number of seconds, or a number followed by `ms`, `s` or `m`.
+pub fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (value, 1.0)
+    };
+
+    number
+        .parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * scale).ok())
+        .ok_or_else(|| String::from("expected a duration such as 500ms, 10s or 2m"))
+}
+
+/// Parses a `--max-mem` value: a number of bytes, or a number followed by `K`, `M` or `G` for
+/// KiB, MiB or GiB.
+pub fn parse_size(value: &str) -> std::result::Result<usize, String> {
+    let (number, scale) = match value.char_indices().last() {
+        Some((i, 'K' | 'k')) => (&value[..i], 1 << 10),
+        Some((i, 'M' | 'm')) => (&value[..i], 1 << 20),
+        Some((i, 'G' | 'g')) => (&value[..i], 1 << 30),
+        _ => (value, 1),
+    };
+
+    number
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| number.checked_mul(scale))
+        .ok_or_else(|| String::from("expected a size such as 512M, 2G or a number of bytes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+    use std::time::Duration;
+
+    use super::{current, parse_duration, parse_size, Budget};
+    use crate::Error;
+
+    #[test]
+    fn test_timeout() {
+        let budget = Budget {
+            timeout: Some(Duration::ZERO),
+            max_mem: None,
+        };
+
+        let result = budget.run(|| {
+            std::thread::sleep(Duration::from_millis(1));
+            current().check()?;
+            unreachable!("the budget has run out");
+        });
+        assert!(matches!(result, Err::<(), _>(Error::BudgetExceeded(_))));
+
+        // a solver that doesn't check is caught once it returns
+        assert!(budget.run(|| Ok(())).is_err());
+        assert!(current().check().is_ok());
+    }
+
+    #[test]
+    fn test_max_mem() {
+        let budget = Budget {
+            timeout: None,
+            max_mem: Some(64 << 20),
+        };
+
+        assert!(budget.run(|| Ok(black_box(vec![0u8; 1000]))).is_ok());
+
+        let result = budget.run(|| {
+            let _large = black_box(vec![0u8; 128 << 20]);
+            current().check()
+        });
+        assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("soon").is_err());
+
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("512M"), Ok(512 << 20));
+        assert_eq!(parse_size("2G"), Ok(2 << 30));
+        assert!(parse_size("1.5G").is_err());
+    }
+}
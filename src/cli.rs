@@ -0,0 +1,417 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use clap::error::ErrorKind;
+use clap::{command, value_parser, Arg, ArgAction, ArgMatches, Command};
+use log::LevelFilter;
+
+use crate::bench::{self, Entry};
+use crate::budget::{self, Budget};
+use crate::error::{Error, Result};
+use crate::input::read_input;
+use crate::mem;
+use crate::parallel;
+use crate::render::Target;
+use crate::report;
+use crate::solution::{DynSolution, Part, Solution};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Human,
+    Csv,
+    Json,
+}
+
+/// Environment variable overriding the directory that inputs are looked up in by default.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory holding `dayNN.txt` inputs: `$AOC_INPUT_DIR`, or `inputs` if that isn't set.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+/// The input used for `day` when no path is given.
+pub fn default_input(day: u32) -> PathBuf {
+    input_dir().join(format!("day{:02}.txt", day))
+}
+
+/// The positional input files argument, which may be omitted or repeated.
+pub fn inputs_arg() -> Arg {
+    Arg::new("input")
+        .help(format!(
+            "Input files, or - for stdin [default: ${}/dayNN.txt, or inputs/dayNN.txt]",
+            INPUT_DIR_VAR
+        ))
+        .action(ArgAction::Append)
+        .value_parser(value_parser!(PathBuf))
+}
+
+pub fn inputs(args: &ArgMatches, day: u32) -> Vec<PathBuf> {
+    match args.get_many::<PathBuf>("input") {
+        Some(inputs) => inputs.cloned().collect(),
+        None => vec![default_input(day)],
+    }
+}
+
+/// The `-v` flag, given once for debug logs and twice for trace logs.
+pub fn verbose_arg() -> Arg {
+    Arg::new("verbose")
+        .short('v')
+        .long("verbose")
+        .help("Log what the solvers are doing to stderr; repeat for more detail")
+        .action(ArgAction::Count)
+}
+
+/// The `--threads` option, for how many days or parts of a day run at once.
+pub fn threads_arg() -> Arg {
+    Arg::new("threads")
+        .long("threads")
+        .short('j')
+        .value_name("N")
+        .help("Threads to run independent work on [default: one per CPU]")
+        .value_parser(value_parser!(u32).range(1..))
+}
+
+/// Sizes the thread pool if `--threads` was given. Call once, after [`init_logging`].
+pub fn init_threads(args: &ArgMatches) {
+    if let Some(threads) = args.get_one::<u32>("threads") {
+        parallel::set_threads(*threads as usize);
+    }
+}
+
+/// Sends logs from this crate at the level picked by `-v` to stderr, leaving stdout for answers.
+///
+/// Other crates only log warnings, so that `-vv` isn't drowned out by HTTP internals.
+pub fn init_logging(args: &ArgMatches) {
+    let level = match args.get_count("verbose") {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+
+    env_logger::Builder::new()
+        .filter_level(LevelFilter::Warn)
+        .filter_module(env!("CARGO_CRATE_NAME"), level)
+        .format_timestamp(None)
+        .target(env_logger::Target::Stderr)
+        .init();
+}
+
+/// Prints the diagnostic for `e` to stderr and exits with its exit code.
+pub fn exit_with(e: &Error) -> ! {
+    eprintln!("{}", e.diagnostic());
+    process::exit(e.exit_code());
+}
+
+/// What to report about each run.
+#[derive(Clone, Copy, Debug)]
+pub enum Mode {
+    Answers,
+    Bench(bench::Options),
+    Mem,
+}
+
+/// Arguments selecting the [`Mode`] and output format, shared by every binary that runs
+/// solutions.
+pub fn mode_args() -> [Arg; 4] {
+    [
+        Arg::new("bench")
+            .long("bench")
+            .value_name("N")
+            .help("Time parsing and each part over N iterations instead of printing answers")
+            .value_parser(value_parser!(u32).range(1..)),
+        Arg::new("mem")
+            .long("mem")
+            .help("Report heap usage of parsing and each part instead of printing answers")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("bench"),
+        Arg::new("warmup")
+            .long("warmup")
+            .value_name("N")
+            .help("Untimed iterations to run before benchmarking")
+            .default_value("3")
+            .value_parser(value_parser!(u32)),
+        Arg::new("format")
+            .long("format")
+            .help("Output format; csv requires --bench or --mem")
+            .default_value("human")
+            .value_parser(["human", "csv", "json"]),
+    ]
+}
+
+pub fn mode(args: &ArgMatches) -> Mode {
+    if let Some(iterations) = args.get_one::<u32>("bench") {
+        Mode::Bench(bench::Options {
+            iterations: *iterations as usize,
+            warmup: *args.get_one::<u32>("warmup").unwrap() as usize,
+        })
+    } else if args.get_flag("mem") {
+        Mode::Mem
+    } else {
+        Mode::Answers
+    }
+}
+
+pub fn format(args: &ArgMatches) -> Format {
+    match args.get_one::<String>("format").map(String::as_str) {
+        Some("csv") => Format::Csv,
+        Some("json") => Format::Json,
+        _ => Format::Human,
+    }
+}
+
+/// The `--timeout` and `--max-mem` limits on parsing and each part.
+pub fn budget_args() -> [Arg; 2] {
+    [
+        Arg::new("timeout")
+            .long("timeout")
+            .value_name("DURATION")
+            .help("Stop parsing or a part that runs for longer than this, e.g. 500ms or 10s")
+            .value_parser(budget::parse_duration),
+        Arg::new("max-mem")
+            .long("max-mem")
+            .value_name("SIZE")
+            .help("Stop parsing or a part that grows the heap by more than this, e.g. 512M or 2G")
+            .value_parser(budget::parse_size),
+    ]
+}
+
+pub fn budget(args: &ArgMatches) -> Budget {
+    Budget {
+        timeout: args.get_one("timeout").copied(),
+        max_mem: args.get_one("max-mem").copied(),
+    }
+}
+
+pub fn print_bench(entries: &[Entry], format: Format) {
+    match format {
+        Format::Human => print!("{}", bench::to_table(entries)),
+        Format::Csv => print!("{}", bench::to_csv(entries)),
+        Format::Json => println!("{}", bench::to_json(entries)),
+    }
+}
+
+/// Benchmarks `day` on the input read from `input_path`.
+pub fn bench_input(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    parts: &[Part],
+    options: &bench::Options,
+) -> Result<Vec<Entry>> {
+    let contents = read_input(input_path)?;
+    let measurements = bench::run(solution, &contents, parts, options)?;
+
+    Ok(measurements
+        .into_iter()
+        .map(|measurement| Entry {
+            day,
+            input: input_path.to_path_buf(),
+            measurement,
+        })
+        .collect())
+}
+
+pub fn print_mem(entries: &[mem::Entry], format: Format) {
+    match format {
+        Format::Human => print!("{}", mem::to_table(entries)),
+        Format::Csv => print!("{}", mem::to_csv(entries)),
+        Format::Json => println!("{}", mem::to_json(entries)),
+    }
+}
+
+/// Measures the heap usage of `day` on the input read from `input_path`.
+pub fn mem_input(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    parts: &[Part],
+) -> Result<Vec<mem::Entry>> {
+    let contents = read_input(input_path)?;
+    let usages = mem::run(solution, &contents, parts)?;
+
+    Ok(usages
+        .into_iter()
+        .map(|(phase, usage)| mem::Entry {
+            day,
+            input: input_path.to_path_buf(),
+            phase,
+            usage,
+        })
+        .collect())
+}
+
+pub fn render_arg() -> Arg {
+    Arg::new("render")
+        .long("render")
+        .value_name("PATH")
+        .help("Draw the input and what was found in it to an .svg or .ppm file, or - for the terminal")
+        .value_parser(Target::parse)
+        .conflicts_with_all(["bench", "mem"])
+}
+
+/// Draws `day` on its only input if `--render` was given, exiting with a usage error if there
+/// are several inputs or the day can't be drawn.
+pub fn render(
+    command: &mut Command,
+    args: &ArgMatches,
+    day: u32,
+    solution: &dyn DynSolution,
+    inputs: &[PathBuf],
+) -> Result<()> {
+    let Some(target) = args.get_one::<Target>("render") else {
+        return Ok(());
+    };
+
+    let [input_path] = inputs else {
+        command
+            .error(ErrorKind::ArgumentConflict, "--render takes a single input")
+            .exit();
+    };
+
+    let input = solution.parse(&read_input(input_path)?)?;
+    let Some(canvas) = solution.render(input.as_ref()) else {
+        command
+            .error(
+                ErrorKind::InvalidValue,
+                format!("day {} has no visualisation", day),
+            )
+            .exit();
+    };
+
+    target.write(&canvas)
+}
+
+/// Prints answers for one input, each line prefixed by the input path if `label` is set.
+///
+/// Every part is run, and the error of each one that fails is passed to `fail`, along with the
+/// part unless the input couldn't be read or parsed.
+fn run(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    format: Format,
+    label: bool,
+    budget: &Budget,
+    fail: &mut impl FnMut(&Path, Option<Part>, Error),
+) {
+    let reports = report::solve(day, solution, input_path, &Part::ALL, budget);
+
+    if format == Format::Json {
+        for report in &reports {
+            println!("{}", report.to_json());
+        }
+    }
+
+    for (index, report) in reports.into_iter().enumerate() {
+        let answer = match report.answer {
+            Ok(answer) => answer,
+            // reading or parsing the input failed, which every part repeats
+            Err(_) if report.solve_time.is_none() && index > 0 => continue,
+            Err(e) => {
+                let part = report.solve_time.is_some().then_some(report.part);
+                fail(input_path, part, e);
+                continue;
+            }
+        };
+        if format != Format::Human {
+            continue;
+        }
+
+        if label {
+            println!("{}: Part {}: {}", input_path.display(), report.part, answer);
+        } else {
+            println!("Part {}: {}", report.part, answer);
+        }
+    }
+}
+
+/// Entry point shared by the per-day binaries.
+///
+/// Every input and part is run even if an earlier one fails; the exit code is that of the first error.
+pub fn main<S>(day: u32, solution: S)
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    let mut command = command!()
+        .arg(inputs_arg())
+        .args(mode_args())
+        .args(budget_args().map(|arg| arg.conflicts_with_all(["bench", "mem"])))
+        .arg(render_arg())
+        .arg(threads_arg())
+        .arg(verbose_arg());
+    let args = command.get_matches_mut();
+    init_logging(&args);
+    init_threads(&args);
+
+    let inputs = inputs(&args, day);
+    if let Err(e) = render(&mut command, &args, day, &solution, &inputs) {
+        exit_with(&e);
+    }
+
+    let label = inputs.len() > 1;
+    let format = format(&args);
+
+    let mut code = 0;
+    // errors are prefixed like answers are, with the part when only that part failed
+    let mut fail = |input: &Path, part: Option<Part>, e: Error| {
+        let mut prefix = String::new();
+        if label {
+            prefix.push_str(&format!("{}: ", input.display()));
+        }
+        if let Some(part) = part {
+            prefix.push_str(&format!("Part {}: ", part));
+        }
+
+        eprintln!("{}{}", prefix, e.diagnostic());
+
+        if code == 0 {
+            code = e.exit_code();
+        }
+    };
+
+    match mode(&args) {
+        Mode::Bench(options) => {
+            let mut entries = Vec::new();
+
+            for input in &inputs {
+                match bench_input(day, &solution, input, &Part::ALL, &options) {
+                    Ok(measured) => entries.extend(measured),
+                    Err(e) => fail(input, None, e),
+                }
+            }
+
+            print_bench(&entries, format);
+        }
+        Mode::Mem => {
+            let mut entries = Vec::new();
+
+            for input in &inputs {
+                match mem_input(day, &solution, input, &Part::ALL) {
+                    Ok(measured) => entries.extend(measured),
+                    Err(e) => fail(input, None, e),
+                }
+            }
+
+            print_mem(&entries, format);
+        }
+        Mode::Answers if format == Format::Csv => command
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--format csv is only supported with --bench or --mem",
+            )
+            .exit(),
+        Mode::Answers => {
+            let budget = budget(&args);
+            for input in &inputs {
+                run(day, &solution, input, format, label, &budget, &mut fail);
+            }
+        }
+    }
+
+    process::exit(code);
+}
@@ -0,0 +1,334 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::solution::Part;
+
+/// Environment variable holding the session cookie, which takes precedence over the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the website address, e.g. to point at a local server.
+pub const URL_VAR: &str = "AOC_URL";
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+const YEAR: u32 = 2024;
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// The shortest time allowed between two requests to the website.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    session: Option<String>,
+}
+
+/// The session cookie from `$AOC_SESSION`, or else from `session` in the TOML file at
+/// `config_path`, which need not exist.
+pub fn session(config_path: &Path) -> Result<Option<String>> {
+    if let Some(session) = env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()) {
+        return Ok(Some(session.trim().to_string()));
+    }
+
+    let contents = match fs::read_to_string(config_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(Error::io(config_path, e)),
+    };
+
+    let config: Config = toml::from_str(&contents).map_err(|e| {
+        let message = format!(
+            "invalid config file {}: {}",
+            config_path.display(),
+            e.message()
+        );
+
+        match e.span() {
+            Some(span) => Error::parse_at(&contents, &contents[span.start..], message),
+            None => Error::parse(message),
+        }
+    })?;
+
+    Ok(config.session.map(|session| session.trim().to_string()))
+}
+
+/// The website address: `$AOC_URL`, or the real site if that isn't set.
+pub fn base_url() -> String {
+    env::var(URL_VAR).unwrap_or_else(|_| String::from(DEFAULT_URL))
+}
+
+/// Keeps requests at least `interval` apart, even across separate runs, by recording when the
+/// last one was made in a file.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(stamp: impl Into<PathBuf>, interval: Duration) -> Self {
+        RateLimiter {
+            stamp: stamp.into(),
+            interval,
+        }
+    }
+
+    /// Sleeps until another request is allowed, then records that one is being made.
+    fn wait(&self) -> Result<()> {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|contents| contents.trim().parse().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+
+        // a stamp from the future means the clock changed, so it's ignored
+        if let Some(elapsed) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+
+        if let Some(parent) = self.stamp.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        fs::write(&self.stamp, now.to_string()).map_err(|e| Error::io(&self.stamp, e))
+    }
+}
+
+/// What the website said about a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way.
+    Incorrect,
+    /// An answer was given too recently; another can be submitted after the time left, if the
+    /// response said how long that is.
+    Wait(Option<Duration>),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait(Some(left)) => write!(f, "too soon, wait {}s", left.as_secs()),
+            Outcome::Wait(None) => write!(f, "too soon, wait before answering again"),
+            Outcome::WrongLevel => write!(f, "this part is already solved or not unlocked yet"),
+        }
+    }
+}
+
+/// The text of the `<article>` in an HTML page, with tags removed and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .map(|start| &html[start..])
+        .map(|article| match article.find("</article>") {
+            Some(end) => &article[..end],
+            None => article,
+        })
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses a wait like `1m 5s` out of "You have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("you have ")? + "you have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (number, unit) = token.split_at(token.len().checked_sub(1)?);
+        let number: u64 = number.parse().ok()?;
+
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Works out the [`Outcome`] from the page returned after submitting an answer.
+pub fn parse_outcome(html: &str) -> Result<Outcome> {
+    let text = article_text(html);
+    let lower = text.to_lowercase();
+
+    if lower.contains("that's the right answer") {
+        Ok(Outcome::Correct)
+    } else if lower.contains("that's not the right answer") {
+        if lower.contains("too high") {
+            Ok(Outcome::TooHigh)
+        } else if lower.contains("too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Incorrect)
+        }
+    } else if lower.contains("you gave an answer too recently") {
+        Ok(Outcome::Wait(parse_wait(&lower)))
+    } else if lower.contains("you don't seem to be solving the right level") {
+        Ok(Outcome::WrongLevel)
+    } else {
+        let mut excerpt: String = text.chars().take(200).collect();
+        if excerpt.is_empty() {
+            excerpt = String::from("an empty page");
+        }
+
+        Err(Error::Http(format!("unrecognised response: {}", excerpt)))
+    }
+}
+
+/// Talks to the Advent of Code website on behalf of a logged in user.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(
+        base_url: impl Into<String>,
+        session: impl Into<String>,
+        limiter: RateLimiter,
+    ) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent,
+            limiter,
+        }
+    }
+
+    fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn send(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String> {
+        self.limiter.wait()?;
+
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => response.into_string().map_err(|e| {
+                Error::Http(format!("could not read the response from {}: {}", url, e))
+            }),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = body.lines().next().unwrap_or("").trim();
+
+                Err(Error::Http(format!(
+                    "{} returned {}: {}",
+                    url, status, reason
+                )))
+            }
+            Err(ureq::Error::Transport(e)) => Err(Error::Http(e.to_string())),
+        }
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn fetch_input(&self, day: u32) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        self.send(self.agent.get(&url), None)
+    }
+
+    /// Submits `answer` for one part of `day`.
+    pub fn submit(&self, day: u32, part: Part, answer: &str) -> Result<Outcome> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+
+        let html = self.send(
+            self.agent.post(&url),
+            Some(&[("level", level.as_str()), ("answer", answer)]),
+        )?;
+
+        parse_outcome(&html)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_outcome, Outcome};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let correct =
+            page("That's the right answer! You are <span class=\"x\">one gold star</span> closer.");
+        assert_eq!(parse_outcome(&correct).unwrap(), Outcome::Correct);
+
+        let high =
+            page("That's not the right answer; your answer is too high.  Please wait one minute.");
+        assert_eq!(parse_outcome(&high).unwrap(), Outcome::TooHigh);
+
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_outcome(&low).unwrap(), Outcome::TooLow);
+
+        let wrong = page("That's not the right answer.  If you're stuck, ask for hints.");
+        assert_eq!(parse_outcome(&wrong).unwrap(), Outcome::Incorrect);
+
+        let wait = page("You gave an answer too recently.  You have 1m 5s left to wait.");
+        assert_eq!(
+            parse_outcome(&wait).unwrap(),
+            Outcome::Wait(Some(Duration::from_secs(65)))
+        );
+
+        let level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_outcome(&level).unwrap(), Outcome::WrongLevel);
+
+        assert!(parse_outcome("<html>Internal error</html>").is_err());
+    }
+}
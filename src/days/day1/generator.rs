@@ -0,0 +1,20 @@
+use crate::rng::Rng;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let lines = 1000 * scale;
+    let left: Vec<u64> = (0..lines).map(|_| rng.range(10000..=99999)).collect();
+
+    let mut out = String::new();
+    for a in &left {
+        // reuse some ids from the left list so that part 2 has similarities to find
+        let b = if rng.chance(0.3) {
+            *rng.choose(&left)
+        } else {
+            rng.range(10000..=99999)
+        };
+
+        out.push_str(&format!("{}   {}\n", a, b));
+    }
+
+    out
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use log::{debug, trace};
+
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
+    let mut list1 = Vec::new();
+    let mut list2 = Vec::new();
+
+    for line in input.lines() {
+        let nums: Vec<_> = line.split(' ').collect();
+        let first = nums.first().expect("could not get first");
+        let last = nums.last().expect("could not get last");
+
+        let num1: i32 = first
+            .parse()
+            .map_err(|e| Error::parse_at(input, first, format!("invalid location id: {}", e)))?;
+        let num2: i32 = last
+            .parse()
+            .map_err(|e| Error::parse_at(input, last, format!("invalid location id: {}", e)))?;
+
+        list1.push(num1);
+        list2.push(num2);
+    }
+
+    list1.sort();
+    list2.sort();
+
+    debug!("{} location ids in each list", list1.len());
+
+    Ok((list1, list2))
+}
+
+fn part1(list1: &[i32], list2: &[i32]) -> Result<i32> {
+    let overflow = || Error::Overflow(String::from("total distance does not fit in an i32"));
+    let mut diff: i32 = 0;
+
+    for (a, b) in list1.iter().zip(list2) {
+        let distance = a
+            .checked_sub(*b)
+            .and_then(i32::checked_abs)
+            .ok_or_else(overflow)?;
+        trace!("{} and {} are {} apart", a, b, distance);
+        diff = diff.checked_add(distance).ok_or_else(overflow)?;
+    }
+
+    Ok(diff)
+}
+
+fn part2(list1: &[i32], list2: &[i32]) -> Result<i32> {
+    let overflow = || Error::Overflow(String::from("similarity score does not fit in an i32"));
+
+    let mut count_right = HashMap::new();
+    for item in list2 {
+        if let Some(count) = count_right.get_mut(item) {
+            *count += 1;
+        } else {
+            count_right.insert(item, 1);
+        }
+    }
+
+    let mut total: i32 = 0;
+    for item in list1 {
+        let count = count_right.get(item).copied().unwrap_or(0);
+        trace!("{} appears {} times in the right list", item, count);
+
+        total = item
+            .checked_mul(count)
+            .and_then(|score| total.checked_add(score))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(total)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (list1, list2): &Self::Input) -> Result<i32> {
+        part1(list1, list2)
+    }
+
+    fn part2(&self, (list1, list2): &Self::Input) -> Result<i32> {
+        part2(list1, list2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day1;
+    use crate::{Error, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day1.parse(EXAMPLE).unwrap();
+        assert_eq!(Day1.part1(&input).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day1.parse(EXAMPLE).unwrap();
+        assert_eq!(Day1.part2(&input).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day1.parse("-2147483648   2147483647\n").unwrap();
+        assert!(matches!(Day1.part1(&input), Err(Error::Overflow(_))));
+
+        let input = Day1
+            .parse("2147483647   2147483647\n2   2147483647\n")
+            .unwrap();
+        assert!(matches!(Day1.part2(&input), Err(Error::Overflow(_))));
+    }
+}
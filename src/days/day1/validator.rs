@@ -0,0 +1,52 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    for line in content.lines() {
+        if line.contains(|c: char| c.is_whitespace() && c != ' ') {
+            violations.at(line, "location ids must be separated by spaces");
+        }
+
+        let ids: Vec<_> = line.split_whitespace().collect();
+        if ids.len() != 2 {
+            violations.at(
+                line,
+                format!("expected 2 location ids, found {}", ids.len()),
+            );
+        }
+
+        for id in ids {
+            violations.number::<i32>(id, "location id");
+        }
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("3   4\n1 2 3\n4\tx\n2147483648   -5\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "expected 2 location ids, found 3 at line 2, column 1",
+                "location ids must be separated by spaces at line 3, column 1",
+                "invalid location id \"x\" at line 3, column 3",
+                "invalid location id \"2147483648\" at line 4, column 1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::VecDeque;
+
+use crate::rng::Rng;
+use crate::Grid;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 45 * scale;
+
+    // hills that slope down by one in every direction from random peaks, so there are plenty
+    // of trails, with the rest of the map at random heights
+    let mut heights: Grid<Option<u8>> = Grid::from_fn(size, size, |_, _| None);
+    let mut queue = VecDeque::new();
+
+    for _ in 0..size * size / 100 {
+        let peak = (rng.index(size), rng.index(size));
+        heights[peak] = Some(9);
+        queue.push_back(peak);
+    }
+
+    while let Some((x, y)) = queue.pop_front() {
+        let height = heights[(x, y)].unwrap();
+        if height == 0 {
+            continue;
+        }
+
+        let neighbors: Vec<_> = heights.neighbors4(x, y).collect();
+        for neighbor in neighbors {
+            if heights[neighbor].is_none() {
+                heights[neighbor] = Some(height - 1);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    heights
+        .map(|height| {
+            let height = match height {
+                Some(height) if !rng.chance(0.05) => *height,
+                _ => rng.range(0..=9) as u8,
+            };
+
+            char::from(b'0' + height)
+        })
+        .to_string()
+}
@@ -0,0 +1,296 @@
+use std::collections::HashSet;
+
+use log::debug;
+
+use crate::geometry::Vec2;
+use crate::render::{Canvas, Cell, Color};
+use crate::{Grid, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+fn find_trailheads(grid: &Grid<u8>) -> Vec<Vec2<usize>> {
+    grid.iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|((x, y), _)| Vec2::new(x, y))
+        .collect()
+}
+
+// use DFS to find peaks
+fn find_unique_peaks(grid: &Grid<u8>, trailhead: Vec2<usize>) -> usize {
+    let mut peaks: HashSet<Vec2<usize>> = HashSet::new();
+
+    let mut stack = Vec::new();
+    stack.push(trailhead);
+
+    while let Some(p) = stack.pop() {
+        let current = grid[(p.x, p.y)];
+
+        // we have found a peak
+        if current == 9 {
+            peaks.insert(p);
+            continue;
+        }
+
+        // add neighbors if they are viable
+        for (x, y) in grid.neighbors4(p.x, p.y) {
+            if grid[(x, y)] == current + 1 {
+                stack.push(Vec2::new(x, y));
+            }
+        }
+    }
+
+    peaks.len()
+}
+
+// use DFS to find peaks
+fn find_paths_to_peaks(grid: &Grid<u8>, trailhead: Vec2<usize>) -> usize {
+    let mut encountered_peaks = 0;
+
+    let mut stack = Vec::new();
+    stack.push(trailhead);
+
+    while let Some(p) = stack.pop() {
+        let current = grid[(p.x, p.y)];
+
+        // we have found a peak
+        if current == 9 {
+            encountered_peaks += 1;
+            continue;
+        }
+
+        // add neighbors if they are viable
+        for (x, y) in grid.neighbors4(p.x, p.y) {
+            if grid[(x, y)] == current + 1 {
+                stack.push(Vec2::new(x, y));
+            }
+        }
+    }
+
+    encountered_peaks
+}
+
+fn part1(grid: &Grid<u8>) -> usize {
+    let trailheads = find_trailheads(grid);
+
+    trailheads
+        .iter()
+        .map(|trailhead| {
+            let score = find_unique_peaks(grid, *trailhead);
+            debug!("trailhead at {} has a score of {}", trailhead, score);
+
+            score
+        })
+        .sum()
+}
+
+fn part2(grid: &Grid<u8>) -> usize {
+    let trailheads = find_trailheads(grid);
+
+    trailheads
+        .iter()
+        .map(|trailhead| {
+            let rating = find_paths_to_peaks(grid, *trailhead);
+            debug!("trailhead at {} has a rating of {}", trailhead, rating);
+
+            rating
+        })
+        .sum()
+}
+
+fn parse_grid(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| c.to_digit(10).map(|x| x as u8))
+}
+
+// cells from which some uphill trail leads to a peak
+fn leads_to_peak(grid: &Grid<u8>) -> Grid<bool> {
+    let mut leads = grid.map(|height| *height == 9);
+
+    for height in (0..9).rev() {
+        for (x, y) in grid.positions() {
+            if grid[(x, y)] == height {
+                leads[(x, y)] = grid
+                    .neighbors4(x, y)
+                    .any(|n| grid[n] == height + 1 && leads[n]);
+            }
+        }
+    }
+
+    leads
+}
+
+// colours every trail by the rating of its trailhead, with the highest rating winning where
+// trails cross
+fn render(grid: &Grid<u8>) -> Canvas {
+    let leads = leads_to_peak(grid);
+    let mut ratings: Grid<Option<usize>> = grid.map(|_| None);
+
+    for trailhead in find_trailheads(grid) {
+        let rating = find_paths_to_peaks(grid, trailhead);
+        let mut stack = vec![(trailhead.x, trailhead.y)];
+
+        while let Some(p) = stack.pop() {
+            if !leads[p] || ratings[p] >= Some(rating) {
+                continue;
+            }
+
+            ratings[p] = Some(rating);
+            stack.extend(
+                grid.neighbors4(p.0, p.1)
+                    .filter(|n| grid[*n] == grid[p] + 1),
+            );
+        }
+    }
+
+    let max_rating = ratings.iter().filter_map(|(_, r)| *r).max().unwrap_or(1);
+    let mut canvas = Canvas::from_grid(grid, |height| {
+        Cell::new(char::from(b'0' + height), Color::DIM)
+    });
+
+    for ((x, y), rating) in ratings.iter() {
+        if let Some(rating) = rating {
+            let t = *rating as f64 / max_rating as f64;
+            let color = Color::gradient(Color::BLUE, Color::RED, t);
+
+            canvas.set(x, y, Cell::new(char::from(b'0' + grid[(x, y)]), color));
+        }
+    }
+
+    canvas
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_grid(input.trim())
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<usize> {
+        Ok(part1(grid))
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<usize> {
+        Ok(part2(grid))
+    }
+
+    fn render(&self, grid: &Self::Input) -> Option<Canvas> {
+        Some(render(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{find_paths_to_peaks, find_trailheads, find_unique_peaks, Day10};
+    use crate::geometry::Direction;
+    use crate::{Grid, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    // lowers cells to 0 until none can be reached from more than one lower neighbour, so that
+    // trails may fork but never join again
+    fn make_single_path(grid: &mut Grid<u8>) {
+        loop {
+            let merge = grid.positions().find(|&(x, y)| {
+                let height = grid[(x, y)];
+                height > 0
+                    && grid
+                        .neighbors4(x, y)
+                        .filter(|n| grid[*n] == height - 1)
+                        .count()
+                        > 1
+            });
+
+            match merge {
+                Some(position) => grid[position] = 0,
+                None => return,
+            }
+        }
+    }
+
+    // small maps with trails laid over random heights
+    fn single_path_map() -> impl Strategy<Value = Grid<u8>> {
+        (2..10usize, 2..10usize)
+            .prop_flat_map(|(width, height)| {
+                // a start, a heading and then whether to go straight, left or right at each step
+                let trail = (
+                    0..width,
+                    0..height,
+                    0..4usize,
+                    proptest::array::uniform9(0..3u8),
+                );
+
+                (
+                    Just(width),
+                    proptest::collection::vec(0..=9u8, width * height),
+                    proptest::collection::vec(trail, 0..4),
+                )
+            })
+            .prop_map(|(width, cells, trails)| {
+                let height = cells.len() / width;
+                let mut grid = Grid::new(width, height, cells).unwrap();
+
+                for (mut x, mut y, heading, turns) in trails {
+                    let mut heading = Direction::ALL[heading];
+                    grid[(x, y)] = 0;
+
+                    for (step, turn) in turns.into_iter().enumerate() {
+                        heading = match turn {
+                            0 => heading,
+                            1 => heading.turn_left(),
+                            _ => heading.turn_right(),
+                        };
+
+                        let offset = heading.offset::<isize>();
+                        let next = x
+                            .checked_add_signed(offset.x)
+                            .zip(y.checked_add_signed(offset.y))
+                            .filter(|(nx, ny)| grid.in_bounds(*nx, *ny));
+
+                        let Some(next) = next else {
+                            break;
+                        };
+
+                        (x, y) = next;
+                        grid[(x, y)] = step as u8 + 1;
+                    }
+                }
+
+                make_single_path(&mut grid);
+                grid
+            })
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day10.parse(EXAMPLE).unwrap();
+        assert_eq!(Day10.part1(&input).unwrap(), 36);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day10.parse(EXAMPLE).unwrap();
+        assert_eq!(Day10.part2(&input).unwrap(), 81);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_peaks_match_paths_on_single_path_maps(grid in single_path_map()) {
+            for trailhead in find_trailheads(&grid) {
+                prop_assert_eq!(
+                    find_unique_peaks(&grid, trailhead),
+                    find_paths_to_peaks(&grid, trailhead),
+                    "trailhead at {}\n{}",
+                    trailhead,
+                    grid.map(|height| char::from(b'0' + height))
+                );
+            }
+        }
+    }
+}
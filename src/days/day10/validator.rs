@@ -0,0 +1,32 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    violations.grid(|c| c.is_ascii_digit(), "a height from 0 to 9");
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("0123\n45x6\n78\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "unexpected character 'x', expected a height from 0 to 9 at line 2, column 3",
+                "row has 2 columns, expected 4 at line 3, column 1",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the map is empty"]);
+    }
+}
@@ -0,0 +1,12 @@
+use crate::rng::Rng;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let stones: Vec<_> = (0..8 * scale)
+        .map(|_| {
+            let digits = rng.range(1..=7) as u32;
+            rng.below(10u64.pow(digits)).to_string()
+        })
+        .collect();
+
+    format!("{}\n", stones.join(" "))
+}
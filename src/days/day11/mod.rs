@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use log::debug;
+
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+fn parse_stones(input: &str) -> Result<Vec<u64>> {
+    input
+        .split(' ')
+        .map(|x| {
+            x.parse::<u64>()
+                .map_err(|e| Error::parse_at(input, x, format!("invalid stone: {}", e)))
+        })
+        .collect()
+}
+
+// returns None if a stone or the number of stones overflows
+fn blink_stone(stone: u64, count: u64, memo: &mut HashMap<(u64, u64), u64>) -> Option<u64> {
+    // no more expansions, so only one element
+    if count == 0 {
+        return Some(1);
+    }
+
+    // we have seen this call before
+    // return cached value
+    if let Some(v) = memo.get(&(stone, count)) {
+        return Some(*v);
+    }
+
+    let result = if stone == 0 {
+        blink_stone(1, count - 1, memo)?
+    } else if stone.to_string().len().is_multiple_of(2) {
+        let s = stone.to_string();
+        let half = s.len() / 2;
+
+        let left = (s[..half]).parse().unwrap();
+        let right = (s[half..]).parse().unwrap();
+
+        blink_stone(left, count - 1, memo)?.checked_add(blink_stone(right, count - 1, memo)?)?
+    } else {
+        blink_stone(stone.checked_mul(2024)?, count - 1, memo)?
+    };
+
+    memo.insert((stone, count), result);
+    Some(result)
+}
+
+fn blink(stones: &[u64], count: u64) -> Result<u64> {
+    let mut memo = HashMap::new();
+
+    let total = stones.iter().try_fold(0u64, |total, stone| {
+        let expanded = blink_stone(*stone, count, &mut memo)?;
+        debug!(
+            "stone {} becomes {} stones after {} blinks",
+            stone, expanded, count
+        );
+
+        total.checked_add(expanded)
+    });
+
+    debug!("{} stone and blink count pairs memoised", memo.len());
+
+    total.ok_or(Error::Overflow(format!(
+        "stones do not fit in a u64 after {} blinks",
+        count
+    )))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_stones(input.trim())
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Result<u64> {
+        blink(stones, 25)
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Result<u64> {
+        blink(stones, 75)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blink, Day11};
+    use crate::Solution;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_blink() {
+        let stones = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(blink(&stones, 6).unwrap(), 22);
+        assert_eq!(blink(&stones, 25).unwrap(), 55312);
+    }
+
+    #[test]
+    fn test_part2() {
+        let stones = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part2(&stones).unwrap(), 65601038650482);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    let mut lines = content.lines();
+    if let Some(stones) = lines.next() {
+        for stone in stones.split(' ') {
+            violations.number::<u64>(stone, "stone");
+        }
+    }
+
+    for line in lines {
+        violations.at(line, "the stones are on a single line");
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("125  -1\n0\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "missing stone at line 1, column 5",
+                "invalid stone \"-1\" at line 1, column 6",
+                "the stones are on a single line at line 2, column 1",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the input is empty"]);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::rng::Rng;
+use crate::Grid;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 140 * scale;
+    let mut plots: Grid<Option<char>> = Grid::from_fn(size, size, |_, _| None);
+
+    // grow regions from random seeds, expanding from a random point on the frontier each time
+    // so that they end up with irregular shapes
+    let mut frontier = Vec::new();
+    for _ in 0..size * size / 40 {
+        let (x, y) = (rng.index(size), rng.index(size));
+        if plots[(x, y)].is_none() {
+            plots[(x, y)] = Some(char::from(b'A' + rng.below(26) as u8));
+            frontier.push((x, y));
+        }
+    }
+
+    while !frontier.is_empty() {
+        let (x, y) = frontier.swap_remove(rng.index(frontier.len()));
+        let plant = plots[(x, y)];
+
+        let neighbors: Vec<_> = plots.neighbors4(x, y).collect();
+        for (nx, ny) in neighbors {
+            if plots[(nx, ny)].is_none() {
+                plots[(nx, ny)] = plant;
+                frontier.push((nx, ny));
+            }
+        }
+    }
+
+    plots
+        .map(|plant| plant.expect("every plot is reachable from a seed"))
+        .to_string()
+}
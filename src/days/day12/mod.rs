@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+
+use log::debug;
+
+use crate::geometry::{Direction, Direction8, Vec2};
+use crate::parallel;
+use crate::render::{Canvas, Cell, Color};
+use crate::{Error, Grid, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+type Point = Vec2<i32>;
+type Region = Vec<Point>;
+
+fn get_cell(grid: &Grid<char>, index: Point) -> Option<char> {
+    grid.get(index.x, index.y).copied()
+}
+
+fn cell_not_matches_target(grid: &Grid<char>, index: Point, target: char) -> bool {
+    match get_cell(grid, index) {
+        Some(value) => value != target,
+        None => true,
+    }
+}
+
+fn traverse_region(
+    visited: &mut HashSet<Point>,
+    grid: &Grid<char>,
+    start: Point,
+) -> Option<Region> {
+    let target = get_cell(grid, start)?;
+
+    let mut region = Vec::new();
+    let mut stack = Vec::from([start]);
+
+    while let Some(current) = stack.pop() {
+        let Some(value) = get_cell(grid, current) else {
+            continue;
+        };
+
+        if visited.contains(&current) || value != target {
+            continue;
+        }
+
+        // add to the region
+        region.push(current);
+        visited.insert(current);
+
+        // iterate neighbors
+        for direction in Direction::ALL {
+            stack.push(current.step(direction));
+        }
+    }
+
+    Some(region)
+}
+
+fn find_regions(grid: &Grid<char>) -> Option<Vec<Region>> {
+    if grid.is_empty() {
+        return None;
+    }
+
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut regions = Vec::new();
+
+    for (x, y) in grid.positions() {
+        let p = Point::new(x.try_into().unwrap(), y.try_into().unwrap());
+
+        if visited.contains(&p) {
+            continue;
+        }
+
+        let mut region =
+            traverse_region(&mut visited, grid, p).expect("error occurred while traversing region");
+
+        region.sort();
+
+        regions.push(region);
+    }
+
+    // sort region lists for deterministic ordering
+    regions.sort();
+
+    Some(regions)
+}
+
+fn region_area(region: &Region) -> usize {
+    region.len()
+}
+
+fn region_perimeter(grid: &Grid<char>, region: &Region) -> Option<usize> {
+    let mut perimeter = 0;
+
+    for p in region {
+        let plant = get_cell(grid, *p)?;
+
+        perimeter += Direction::ALL
+            .iter()
+            .filter(|direction| cell_not_matches_target(grid, p.step(**direction), plant))
+            .count();
+    }
+
+    Some(perimeter)
+}
+
+fn region_sides(grid: &Grid<char>, region: &Region) -> Option<usize> {
+    let mut total_sides = 0;
+
+    let mut north_candidates = HashSet::new();
+    let mut south_candidates = HashSet::new();
+    let mut west_candidates = HashSet::new();
+    let mut east_candidates = HashSet::new();
+
+    for p in region {
+        let plant = get_cell(grid, *p)?;
+
+        let north = p.step(Direction::Up);
+        let south = p.step(Direction::Down);
+        let west = p.step(Direction::Left);
+        let east = p.step(Direction::Right);
+
+        let north_west = *p + Direction8::NorthWest.offset();
+        let north_east = *p + Direction8::NorthEast.offset();
+        let south_west = *p + Direction8::SouthWest.offset();
+        let south_east = *p + Direction8::SouthEast.offset();
+
+        if cell_not_matches_target(grid, north, plant) {
+            if !north_candidates.contains(&north_west) && !north_candidates.contains(&north_east) {
+                total_sides += 1;
+            }
+
+            north_candidates.insert(north);
+        }
+
+        if cell_not_matches_target(grid, south, plant) {
+            if !south_candidates.contains(&south_west) && !south_candidates.contains(&south_east) {
+                total_sides += 1;
+            }
+
+            south_candidates.insert(south);
+        }
+
+        if cell_not_matches_target(grid, west, plant) {
+            if !west_candidates.contains(&north_west) && !west_candidates.contains(&south_west) {
+                total_sides += 1;
+            }
+
+            west_candidates.insert(west);
+        }
+
+        if cell_not_matches_target(grid, east, plant) {
+            if !east_candidates.contains(&north_east) && !east_candidates.contains(&south_east) {
+                total_sides += 1;
+            }
+
+            east_candidates.insert(east);
+        }
+    }
+
+    Some(total_sides)
+}
+
+fn part1(grid: &Grid<char>, regions: &[Region]) -> Option<usize> {
+    parallel::map(regions, |region| {
+        let perimeter = region_perimeter(grid, region)?;
+        let area = region_area(region);
+        debug!(
+            "{} region at {}: area {}, perimeter {}",
+            get_cell(grid, region[0])?,
+            region[0],
+            area,
+            perimeter
+        );
+
+        Some(perimeter * area)
+    })
+    .into_iter()
+    .sum()
+}
+
+fn part2(grid: &Grid<char>, regions: &[Region]) -> Option<usize> {
+    parallel::map(regions, |region| {
+        let sides = region_sides(grid, region)?;
+        let area = region_area(region);
+        debug!(
+            "{} region at {}: area {}, {} sides",
+            get_cell(grid, region[0])?,
+            region[0],
+            area,
+            sides
+        );
+
+        Some(sides * area)
+    })
+    .into_iter()
+    .sum()
+}
+
+// each region gets its own colour, with fences wherever a plot borders another plant
+fn render(grid: &Grid<char>, regions: &[Region]) -> Canvas {
+    let mut canvas = Canvas::from_grid(grid, |plant| Cell::new(*plant, Color::WHITE));
+
+    for (index, region) in regions.iter().enumerate() {
+        let color = Color::category(index);
+
+        for p in region {
+            let plant = grid[(p.x as usize, p.y as usize)];
+            canvas.set(p.x, p.y, Cell::new(plant, color.darken(0.4)).on(color));
+
+            for direction in Direction::ALL {
+                if cell_not_matches_target(grid, p.step(direction), plant) {
+                    canvas.fence(p.x, p.y, direction);
+                }
+            }
+        }
+    }
+
+    canvas
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = (Grid<char>, Vec<Region>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input.trim(), Some)?;
+
+        let regions = find_regions(&grid).ok_or(Error::parse("could not find regions"))?;
+
+        Ok((grid, regions))
+    }
+
+    fn part1(&self, (grid, regions): &Self::Input) -> Result<usize> {
+        part1(grid, regions).ok_or(Error::Unsolvable(String::from("could not solve part 1")))
+    }
+
+    fn part2(&self, (grid, regions): &Self::Input) -> Result<usize> {
+        part2(grid, regions).ok_or(Error::Unsolvable(String::from("could not solve part 2")))
+    }
+
+    fn render(&self, (grid, regions): &Self::Input) -> Option<Canvas> {
+        Some(render(grid, regions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_regions, region_perimeter, render, Day12, Region};
+    use crate::geometry::Vec2;
+    use crate::{Grid, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[rustfmt::skip::macros(vec)]
+    #[test]
+    fn test_find_regions() {
+        let data = [
+            vec!['O', 'O', 'O', 'O', 'O'],
+            vec!['O', 'X', 'O', 'X', 'O'],
+            vec!['O', 'O', 'O', 'O', 'O'],
+            vec!['O', 'X', 'O', 'X', 'O'],
+            vec!['O', 'O', 'O', 'O', 'O'],
+        ];
+
+        let expected_regions = vec![
+            vec![
+                (0, 0), (1, 0), (2, 0), (3, 0), (4, 0), 
+                (0, 1),         (2, 1),         (4, 1),
+                (0, 2), (1, 2), (2, 2), (3, 2), (4, 2),
+                (0, 3),         (2, 3),         (4, 3),
+                (0, 4), (1, 4), (2, 4), (3, 4), (4, 4),
+            ],
+            vec![(1, 1)],
+            vec![(1, 3)],
+            vec![(3, 1)],
+            vec![(3, 3)],
+        ];
+
+        let mut expected_regions: Vec<Region> = expected_regions
+            .into_iter()
+            .map(|region| region.into_iter().map(Vec2::from).collect())
+            .collect();
+
+        expected_regions.iter_mut().for_each(|region| region.sort());
+        expected_regions.sort();
+
+        let grid = Grid::new(5, 5, data.concat()).expect("grid should be square");
+        let regions = find_regions(&grid).expect("error finding regions");
+        assert_eq!(regions, expected_regions);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part1(&input).unwrap(), 1930);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part2(&input).unwrap(), 1206);
+    }
+
+    #[test]
+    fn test_render_fences_perimeters() {
+        let (grid, regions) = Day12.parse(EXAMPLE).unwrap();
+        let canvas = render(&grid, &regions);
+
+        let perimeter: usize = regions
+            .iter()
+            .map(|region| region_perimeter(&grid, region).unwrap())
+            .sum();
+        assert_eq!(canvas.fence_count(), perimeter);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::rng::Rng;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut machines = Vec::new();
+
+    while machines.len() < 320 * scale {
+        let (ax, ay) = (rng.range(10..=99), rng.range(10..=99));
+        let (bx, by) = (rng.range(10..=99), rng.range(10..=99));
+
+        // parallel buttons don't have a unique solution, and real inputs never have them
+        if ax * by == ay * bx {
+            continue;
+        }
+
+        let (px, py) = if rng.chance(0.5) {
+            let (a, b) = (rng.range(0..=100), rng.range(0..=100));
+            (a * ax + b * bx, a * ay + b * by)
+        } else {
+            (rng.range(1000..=20000), rng.range(1000..=20000))
+        };
+
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            ax, ay, bx, by, px, py
+        ));
+    }
+
+    machines.join("\n")
+}
@@ -0,0 +1,175 @@
+use log::debug;
+
+use crate::geometry::Vec2;
+use crate::parallel;
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+mod parser;
+pub(crate) mod validator;
+
+#[derive(Debug)]
+pub struct Machine {
+    pub button_a: Vec2<u64>,
+    pub button_b: Vec2<u64>,
+    pub prize: Vec2<u64>,
+}
+
+// the number of A and B presses that win the prize, if there is a whole number of each
+fn presses(machine: &Machine) -> Option<(f64, f64)> {
+    // following variables represent a 3x2 matrix
+    // a b c
+    // d e f
+
+    let a = machine.button_a.x as f64;
+    let b = machine.button_b.x as f64;
+    let c = machine.prize.x as f64;
+    let d = machine.button_a.y as f64;
+    let e = machine.button_b.y as f64;
+    let f = machine.prize.y as f64;
+
+    // scale the bottom row by a and subtract d times the top row, which leaves 0 in place of d
+    // and the determinant in place of e
+    let e = e * a - b * d;
+    let f = f * a - c * d;
+
+    // without a single solution, or when the bottom row was scaled by 0, there is no number of
+    // presses to give
+    if e == 0.0 || a == 0.0 {
+        return None;
+    }
+
+    // solve bottom row
+    let y = f / e;
+
+    // solve top row using y
+    let x = (c - b * y) / a;
+
+    if x.fract() == 0.0 && y.fract() == 0.0 {
+        return Some((x, y));
+    }
+
+    None
+}
+
+// total tokens to win every prize that can be won
+fn total_cost(machines: &[Machine]) -> f64 {
+    parallel::map_indexed(machines, |index, machine| match presses(machine) {
+        Some((a, b)) => {
+            let cost = 3.0 * a + b;
+            debug!(
+                "machine {}: {} A and {} B presses for {} tokens",
+                index + 1,
+                a,
+                b,
+                cost
+            );
+
+            Some(cost)
+        }
+        None => {
+            debug!(
+                "machine {}: the prize at {} can't be won",
+                index + 1,
+                machine.prize
+            );
+
+            None
+        }
+    })
+    .into_iter()
+    .flatten()
+    .sum()
+}
+
+fn part1(machines: &[Machine]) -> f64 {
+    total_cost(machines)
+}
+
+fn part2(machines: &[Machine]) -> Result<f64> {
+    const OFFSET: u64 = 10000000000000;
+
+    let machines = machines
+        .iter()
+        .map(|machine| {
+            let (Some(x), Some(y)) = (
+                machine.prize.x.checked_add(OFFSET),
+                machine.prize.y.checked_add(OFFSET),
+            ) else {
+                return Err(Error::Overflow(format!(
+                    "the prize at {} does not fit in a u64 once corrected",
+                    machine.prize
+                )));
+            };
+
+            Ok(Machine {
+                button_a: machine.button_a,
+                button_b: machine.button_b,
+                prize: Vec2::new(x, y),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(total_cost(&machines))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type Output1 = f64;
+    type Output2 = f64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        // trailing whitespace is left to the parser, so that a missing last line is reported on
+        // the line where it should be
+        parser::parse_machines(input.trim_start()).map_err(|e| Error::from_nom(input, e))
+    }
+
+    fn part1(&self, machines: &Self::Input) -> Result<f64> {
+        Ok(part1(machines))
+    }
+
+    fn part2(&self, machines: &Self::Input) -> Result<f64> {
+        part2(machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{presses, Day13, Machine};
+    use crate::geometry::Vec2;
+    use crate::{Error, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part1(&input).unwrap(), 480.0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part2(&input).unwrap(), 875318608908.0);
+    }
+
+    #[test]
+    fn test_part2_overflow() {
+        let input = Day13
+            .parse("Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=18446744073709551615, Y=5\n")
+            .unwrap();
+        assert!(matches!(Day13.part2(&input), Err(Error::Overflow(_))));
+    }
+
+    #[test]
+    fn test_presses_without_a_single_solution() {
+        let parallel = Machine {
+            button_a: Vec2::new(1, 2),
+            button_b: Vec2::new(2, 4),
+            prize: Vec2::new(3, 6),
+        };
+        assert_eq!(presses(&parallel), None);
+    }
+}
@@ -0,0 +1,124 @@
+use super::Machine;
+use crate::geometry::Vec2;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{line_ending, multispace0, u64};
+use nom::combinator::{all_consuming, cut, map, not, value};
+use nom::multi::count;
+use nom::multi::separated_list0;
+use nom::sequence::{preceded, separated_pair, terminated, tuple};
+use nom::{Finish, IResult};
+
+fn parse_button_a(input: &str) -> IResult<&str, Vec2<u64>> {
+    map(
+        preceded(
+            tag("Button A: "),
+            separated_pair(
+                preceded(tag("X+"), u64),
+                tag(", "),
+                preceded(tag("Y+"), u64),
+            ),
+        ),
+        |(x, y)| Vec2::new(x, y),
+    )(input)
+}
+
+fn parse_button_b(input: &str) -> IResult<&str, Vec2<u64>> {
+    map(
+        preceded(
+            tag("Button B: "),
+            separated_pair(
+                preceded(tag("X+"), u64),
+                tag(", "),
+                preceded(tag("Y+"), u64),
+            ),
+        ),
+        |(x, y)| Vec2::new(x, y),
+    )(input)
+}
+
+fn parse_prize(input: &str) -> IResult<&str, Vec2<u64>> {
+    map(
+        preceded(
+            tag("Prize: "),
+            separated_pair(
+                preceded(tag("X="), u64),
+                tag(", "),
+                preceded(tag("Y="), u64),
+            ),
+        ),
+        |(x, y)| Vec2::new(x, y),
+    )(input)
+}
+
+fn parse_machine(input: &str) -> IResult<&str, Machine> {
+    map(
+        tuple((
+            terminated(parse_button_a, line_ending),
+            terminated(parse_button_b, line_ending),
+            parse_prize,
+        )),
+        |(button_a, button_b, prize)| Machine {
+            button_a,
+            button_b,
+            prize,
+        },
+    )(input)
+}
+
+// the blank line between two machines, as opposed to whitespace at the end of the input
+fn parse_separator(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        terminated(count(line_ending, 2), not(all_consuming(multispace0))),
+    )(input)
+}
+
+// every machine is cut, so that an error inside one is reported where it happened rather than
+// as unexpected input where the list of machines ended
+pub fn parse_machines(input: &str) -> Result<Vec<Machine>, nom::error::Error<&str>> {
+    all_consuming(terminated(
+        separated_list0(parse_separator, cut(parse_machine)),
+        multispace0,
+    ))(input)
+    .finish()
+    .map(|(_, machines)| machines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_machines;
+    use crate::error::{Error, Location};
+
+    fn error_location(input: &str) -> Location {
+        match Error::from_nom(input, parse_machines(input).unwrap_err()) {
+            Error::Parse {
+                location: Some(location),
+                ..
+            } => location,
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_errors_are_located_in_their_machine() {
+        let machine = "Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=5, Y=6\n";
+        assert_eq!(
+            parse_machines(&format!("{}\n{}\n", machine, machine))
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let bad_prize = format!(
+            "{}\nButton A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=a, Y=6\n",
+            machine
+        );
+        let location = error_location(&bad_prize);
+        assert_eq!((location.line, location.column), (7, 10));
+
+        let missing_prize = "Button A: X+1, Y+2\nButton B: X+3, Y+4\n";
+        let location = error_location(missing_prize);
+        assert_eq!((location.line, location.column), (3, 1));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::validate::{Violation, Violations};
+
+/// Checks a line like `Button A: X+94, Y+34`, made of `label`, a number, `separator` and
+/// another number.
+fn check_line(violations: &mut Violations, line: &str, label: &str, separator: &str) {
+    let Some(rest) = line.strip_prefix(label) else {
+        violations.at(line, format!("expected a line starting with {:?}", label));
+        return;
+    };
+
+    let Some((x, y)) = rest.split_once(separator) else {
+        violations.at(
+            rest,
+            format!("expected {:?} between the numbers", separator),
+        );
+        return;
+    };
+
+    violations.number::<u32>(x, "number");
+    violations.number::<u32>(y, "number");
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    let lines: Vec<_> = content.lines().collect();
+    for machine in lines.split(|line| line.is_empty()) {
+        let [a, b, prize] = machine else {
+            let at = machine.first().copied().unwrap_or(content);
+            violations.at(
+                at,
+                format!("a machine has 3 lines, this one has {}", machine.len()),
+            );
+            continue;
+        };
+
+        check_line(&mut violations, a, "Button A: X+", ", Y+");
+        check_line(&mut violations, b, "Button B: X+", ", Y+");
+        check_line(&mut violations, prize, "Prize: X=", ", Y=");
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate(
+            "Button A: X+1, Y+2\nButton B: X+3\nPrize: X=5, Y=six\n\nButton A: X+1, Y+2\n",
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            found,
+            [
+                "expected \", Y+\" between the numbers at line 2, column 13",
+                "invalid number \"six\" at line 3, column 15",
+                "a machine has 3 lines, this one has 1 at line 5, column 1",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the input is empty"]);
+    }
+}
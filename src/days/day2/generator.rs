@@ -0,0 +1,31 @@
+use crate::rng::Rng;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..1000 * scale {
+        let len = rng.range(5..=8) as usize;
+        let increasing = rng.chance(0.5);
+
+        let mut level = rng.range(30..=70) as i64;
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+
+            let step = rng.range(1..=3) as i64;
+            level += if increasing { step } else { -step };
+        }
+
+        // about half of the reports get a single bad level, which the dampener may or may not fix
+        if rng.chance(0.5) {
+            let index = rng.index(len);
+            levels[index] = rng.range(1..=99) as i64;
+        }
+
+        let levels: Vec<_> = levels.iter().map(i64::to_string).collect();
+        out.push_str(&levels.join(" "));
+        out.push('\n');
+    }
+
+    out
+}
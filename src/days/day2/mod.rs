@@ -0,0 +1,178 @@
+use std::fmt::{self, Display};
+
+use log::debug;
+
+use crate::parallel;
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>> {
+    let mut reports = Vec::new();
+
+    for line in input.lines() {
+        let report = line
+            .split(' ')
+            .map(|level| {
+                level
+                    .parse::<i32>()
+                    .map_err(|e| Error::parse_at(input, level, format!("invalid level: {}", e)))
+            })
+            .collect::<Result<_>>()?;
+
+        reports.push(report);
+    }
+
+    Ok(reports)
+}
+
+// the first reason a report is unsafe
+#[derive(Debug)]
+enum Violation {
+    Step { from: i32, to: i32 },
+    Turn { at: i32 },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Step { from, to } => {
+                write!(f, "{} to {} changes by {}", from, to, from.abs_diff(*to))
+            }
+            Violation::Turn { at } => write!(f, "changes direction at {}", at),
+        }
+    }
+}
+
+fn find_violation(report: &[i32]) -> Option<Violation> {
+    let mut prev_diff = None;
+
+    for items in report.windows(2) {
+        let [a, b] = items else {
+            panic!("items did not have 2 items")
+        };
+
+        // levels far enough apart to overflow are an unsafe step anyway
+        let Some(diff) = b.checked_sub(*a) else {
+            return Some(Violation::Step { from: *a, to: *b });
+        };
+
+        if !(1..=3).contains(&diff.abs()) {
+            return Some(Violation::Step { from: *a, to: *b });
+        }
+
+        if let Some(d) = prev_diff {
+            // if their sign differs
+            if (d < 0 && diff > 0) || (d > 0 && diff < 0) {
+                return Some(Violation::Turn { at: *a });
+            }
+        }
+
+        prev_diff = Some(diff);
+    }
+
+    None
+}
+
+fn is_safe(report: &[i32]) -> bool {
+    find_violation(report).is_none()
+}
+
+fn part1(reports: &[Vec<i32>]) -> usize {
+    parallel::map_indexed(reports, |index, report| match find_violation(report) {
+        Some(violation) => {
+            debug!("report {} {:?} is unsafe: {}", index + 1, report, violation);
+            false
+        }
+        None => true,
+    })
+    .into_iter()
+    .filter(|safe| *safe)
+    .count()
+}
+
+fn part2(reports: &[Vec<i32>]) -> usize {
+    parallel::map_indexed(reports, |report_index, report| {
+        if is_safe(report) {
+            return true;
+        }
+
+        let removed = report
+            .iter()
+            .enumerate()
+            .map(|(index, _)| {
+                let mut new_report = (*report).clone();
+                new_report.remove(index);
+
+                new_report
+            })
+            .position(|report| is_safe(&report));
+
+        match removed {
+            Some(index) => debug!(
+                "report {} {:?} is safe without level {}",
+                report_index + 1,
+                report,
+                index + 1
+            ),
+            None => debug!(
+                "report {} {:?} is unsafe with any one level removed",
+                report_index + 1,
+                report
+            ),
+        }
+
+        removed.is_some()
+    })
+    .into_iter()
+    .filter(|safe| *safe)
+    .count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input.trim())
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<usize> {
+        Ok(part1(reports))
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<usize> {
+        Ok(part2(reports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day2;
+    use crate::Solution;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+        assert_eq!(Day2.part1(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+        assert_eq!(Day2.part2(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_levels_far_apart() {
+        let input = Day2.parse("-2147483648 2147483647\n").unwrap();
+        assert_eq!(Day2.part1(&input).unwrap(), 0);
+        assert_eq!(Day2.part2(&input).unwrap(), 1);
+    }
+}
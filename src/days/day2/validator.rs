@@ -0,0 +1,48 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    for line in content.lines() {
+        if line.is_empty() {
+            violations.at(line, "empty report");
+            continue;
+        }
+
+        for level in line.split(' ') {
+            violations.number::<i32>(level, "level");
+        }
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("7 6 4\n1 x 3\n\n8  2147483648\n-1 -2\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "invalid level \"x\" at line 2, column 3",
+                "empty report at line 3, column 1",
+                "missing level at line 4, column 3",
+                "invalid level \"2147483648\" at line 4, column 4",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the input is empty"]);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::rng::Rng;
+
+const NOISE: &[u8] = b"!@#$%^&*()[]{}<>,;:'?/+-_ =~";
+const DECOYS: &[&str] = &[
+    "what()", "from()", "select()", "when()", "how()", "who()", "why()",
+];
+
+fn token(rng: &mut Rng) -> String {
+    let a = rng.range(1..=999);
+    let b = rng.range(1..=999);
+
+    match rng.below(12) {
+        0..=3 => format!("mul({},{})", a, b),
+        4 => String::from("do()"),
+        5 => String::from("don't()"),
+        6 => format!("mul({},{}]", a, b),
+        7 => format!("mul ( {},{} )", a, b),
+        8 => format!("mul({}!{})", a, b),
+        9 => rng.choose(DECOYS).to_string(),
+        _ => (*rng.choose(NOISE) as char).to_string(),
+    }
+}
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..6 * scale {
+        let mut line = String::new();
+        while line.len() < 3000 {
+            line.push_str(&token(rng));
+        }
+
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,162 @@
+use log::{debug, trace};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take},
+    character::complete::{char, i32},
+    combinator::{all_consuming, map},
+    multi::many0,
+    sequence::{delimited, separated_pair},
+    Finish, IResult,
+};
+
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+enum ParseInstruction {
+    Nop,
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+fn parse_mul(input: &str) -> IResult<&str, ParseInstruction> {
+    map(
+        delimited(tag("mul("), separated_pair(i32, char(','), i32), char(')')),
+        |(a, b)| ParseInstruction::Mul(a, b),
+    )(input)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    let parse_do = map(tag("do()"), |_| ParseInstruction::Do);
+    let parse_dont = map(tag("don't()"), |_| ParseInstruction::Dont);
+    let parse_nop = map(take(1usize), |_| ParseInstruction::Nop);
+    let parse_instruction = alt((parse_mul, parse_do, parse_dont, parse_nop));
+
+    all_consuming(many0(parse_instruction))(input)
+        .finish()
+        .map(|(_, instructions)| {
+            instructions
+                .iter()
+                .filter_map(|instr| match instr {
+                    ParseInstruction::Nop => None,
+                    ParseInstruction::Do => Some(Instruction::Do),
+                    ParseInstruction::Dont => Some(Instruction::Dont),
+                    ParseInstruction::Mul(a, b) => Some(Instruction::Mul(*a, *b)),
+                })
+                .collect::<Vec<_>>()
+        })
+        .inspect(|instructions| debug!("{} instructions", instructions.len()))
+        .map_err(|e| Error::from_nom(input, e))
+}
+
+fn overflow() -> Error {
+    Error::Overflow(String::from("sum of products does not fit in an i32"))
+}
+
+fn part2(instructions: &[Instruction]) -> Result<i32> {
+    let mut should_execute = true;
+    let mut total: i32 = 0;
+
+    for (index, instr) in instructions.iter().enumerate() {
+        match instr {
+            Instruction::Do => {
+                if !should_execute {
+                    debug!("enabled at instruction {}", index + 1);
+                }
+                should_execute = true;
+            }
+            Instruction::Dont => {
+                if should_execute {
+                    debug!("disabled at instruction {}", index + 1);
+                }
+                should_execute = false;
+            }
+            Instruction::Mul(a, b) => {
+                if should_execute {
+                    total = a
+                        .checked_mul(*b)
+                        .and_then(|product| total.checked_add(product))
+                        .ok_or_else(overflow)?;
+                } else {
+                    trace!("skipping mul({},{})", a, b);
+                }
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+fn part1(instructions: &[Instruction]) -> Result<i32> {
+    instructions
+        .iter()
+        .filter_map(|instr| {
+            if let Instruction::Mul(a, b) = instr {
+                Some(a.checked_mul(*b))
+            } else {
+                None
+            }
+        })
+        .try_fold(0i32, |total, product| total.checked_add(product?))
+        .ok_or_else(overflow)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<i32> {
+        part1(instructions)
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<i32> {
+        part2(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day3;
+    use crate::{Error, Solution};
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day3.parse(EXAMPLE1).unwrap();
+        assert_eq!(Day3.part1(&input).unwrap(), 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day3.parse(EXAMPLE2).unwrap();
+        assert_eq!(Day3.part2(&input).unwrap(), 48);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day3.parse("mul(2147483647,2)").unwrap();
+        assert!(matches!(Day3.part1(&input), Err(Error::Overflow(_))));
+        assert!(matches!(Day3.part2(&input), Err(Error::Overflow(_))));
+
+        let input = Day3.parse("mul(2147483647,1)mul(1,1)").unwrap();
+        assert!(matches!(Day3.part1(&input), Err(Error::Overflow(_))));
+    }
+}
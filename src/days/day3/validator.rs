@@ -0,0 +1,75 @@
+use crate::validate::{Violation, Violations};
+
+/// The length of the number with an optional sign at the start of `s`, and whether it was signed.
+fn number_len(s: &str) -> (usize, bool) {
+    let signed = s.starts_with(['+', '-']);
+    let digits = s[signed as usize..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+
+    (signed as usize + digits, signed)
+}
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    if violations.content().is_none() {
+        return violations.into_vec();
+    }
+
+    // the memory may hold anything, but real instructions only have 1-3 digit operands, which
+    // the parser doesn't insist on
+    for (start, _) in input.match_indices("mul(") {
+        let mul = &input[start..];
+        let args = &mul["mul(".len()..];
+
+        let (a_len, a_signed) = number_len(args);
+        let Some(rest) = args[a_len..].strip_prefix(',') else {
+            continue;
+        };
+        let (b_len, b_signed) = number_len(rest);
+        if a_len == a_signed as usize || b_len == b_signed as usize {
+            continue;
+        }
+        if !rest[b_len..].starts_with(')') {
+            continue;
+        }
+
+        for (operand, len, signed) in [(args, a_len, a_signed), (rest, b_len, b_signed)] {
+            if signed {
+                violations.at(operand, "mul operands must not have a sign");
+            } else if len > 3 {
+                violations.at(operand, "mul operands have at most 3 digits");
+            }
+        }
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example1.txt")).is_empty());
+        assert!(validate(include_str!("example2.txt")).is_empty());
+
+        let found: Vec<_> = validate("mul(1234,5)xmul(-1,2)mul(1,+2)\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "mul operands have at most 3 digits at line 1, column 5",
+                "mul operands must not have a sign at line 1, column 17",
+                "mul operands must not have a sign at line 1, column 28",
+            ]
+        );
+
+        let found: Vec<_> = validate("\n").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the input is empty"]);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::rng::Rng;
+use crate::Grid;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 140 * scale;
+    let letters = ['X', 'M', 'A', 'S'];
+
+    Grid::from_fn(size, size, |_, _| *rng.choose(&letters)).to_string()
+}
@@ -0,0 +1,83 @@
+use log::trace;
+
+use crate::{Grid, Result, Solution};
+
+pub(crate) mod generator;
+mod part1;
+pub(crate) mod validator;
+
+fn is_mas(arr: [char; 3]) -> bool {
+    (arr[0] == 'M' && arr[1] == 'A' && arr[2] == 'S')
+        || (arr[0] == 'S' && arr[1] == 'A' && arr[2] == 'M')
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    let height = grid.height();
+    let width = grid.width();
+    if height < 3 || width < 3 {
+        return 0;
+    }
+
+    let mut total = 0;
+    for row in 1..(height - 1) {
+        for col in 1..(width - 1) {
+            let diagonal1 = [
+                grid[(col - 1, row - 1)],
+                grid[(col, row)],
+                grid[(col + 1, row + 1)],
+            ];
+            let diagonal2 = [
+                grid[(col + 1, row - 1)],
+                grid[(col, row)],
+                grid[(col - 1, row + 1)],
+            ];
+
+            if is_mas(diagonal1) && is_mas(diagonal2) {
+                trace!("X-MAS centred at ({}, {})", col, row);
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Grid::parse(input.trim(), Some)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<usize> {
+        Ok(part1::part1(grid))
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<usize> {
+        Ok(part2(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day4;
+    use crate::Solution;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part1(&input).unwrap(), 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part2(&input).unwrap(), 9);
+    }
+}
@@ -0,0 +1,28 @@
+use log::trace;
+
+use crate::Grid;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+pub(crate) fn part1(grid: &Grid<char>) -> usize {
+    let mut total = 0;
+    for (x, y) in grid.positions() {
+        for (dx, dy) in DIRECTIONS {
+            if grid.ray(x, y, dx, dy).take(4).copied().eq("XMAS".chars()) {
+                trace!("XMAS at ({}, {}) going ({}, {})", x, y, dx, dy);
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
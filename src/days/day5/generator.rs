@@ -0,0 +1,38 @@
+use crate::rng::Rng;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    // pages in a random order, with a rule between every pair, so that every update has exactly
+    // one correct ordering
+    let mut pages: Vec<u64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut out = String::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            out.push_str(&format!("{}|{}\n", before, after));
+        }
+    }
+
+    out.push('\n');
+
+    for _ in 0..200 * scale {
+        // updates always have a middle page
+        let len = 2 * rng.range(2..=11) as usize + 1;
+
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(len);
+
+        // keep some updates correctly ordered
+        if rng.chance(0.4) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+
+        let update: Vec<_> = update.iter().map(u64::to_string).collect();
+        out.push_str(&update.join(","));
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+
+use log::debug;
+use nom::error::ErrorKind;
+
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+mod parser;
+pub(crate) mod validator;
+
+type Page = u32;
+type Update = Vec<Page>;
+
+#[derive(Clone, Debug)]
+pub struct OrderRule {
+    before: Page,
+    after: Page,
+}
+
+fn is_sorted(update: &Update, rules: &[OrderRule]) -> bool {
+    let mut edges = rules
+        .iter()
+        .filter(|rule| update.contains(&rule.before) && update.contains(&rule.after))
+        .fold(HashMap::new(), |mut m, rule| {
+            m.entry(rule.after)
+                .or_insert(HashSet::new())
+                .insert(rule.before);
+
+            m
+        });
+
+    for p in update {
+        // we have found a page with incoming edges
+        if edges.contains_key(p) {
+            return false;
+        }
+
+        // remove all incoming edges from p
+        for (_, e) in edges.iter_mut() {
+            e.retain(|q| q != p);
+        }
+
+        // remove any nodes with no incoming edges
+        edges.retain(|_, e| !e.is_empty());
+    }
+
+    true
+}
+
+fn sort(update: &Update, rules: &[OrderRule]) -> Option<Update> {
+    let mut edges = rules
+        .iter()
+        .filter(|rule| update.contains(&rule.before) && update.contains(&rule.after))
+        .fold(HashMap::new(), |mut m, rule| {
+            m.entry(rule.after)
+                .or_insert(HashSet::new())
+                .insert(rule.before);
+
+            m
+        });
+
+    // pages with no incoming edges
+    let mut entries: Vec<_> = update
+        .iter()
+        .copied()
+        .filter(|page| !edges.contains_key(page))
+        .collect();
+
+    let mut sorted_update = Vec::new();
+
+    while let Some(p) = entries.pop() {
+        sorted_update.push(p);
+
+        // remove any incoming edges from p
+        for (_, e) in edges.iter_mut() {
+            e.retain(|q| *q != p);
+        }
+
+        let now_insertable = edges.iter().filter(|(_, e)| e.is_empty()).map(|(q, _)| *q);
+        entries.extend(now_insertable);
+
+        // remove inserted edges
+        edges.retain(|_, e| !e.is_empty())
+    }
+
+    if edges.is_empty() {
+        Some(sorted_update)
+    } else {
+        let mut stuck: Vec<_> = edges.keys().collect();
+        stuck.sort();
+        debug!(
+            "rules for {:?} form a cycle, so pages {:?} can't be placed",
+            update, stuck
+        );
+
+        None
+    }
+}
+
+fn part1(updates: &[Update], rules: &[OrderRule]) -> u32 {
+    updates
+        .iter()
+        .filter(|update| is_sorted(update, rules))
+        .map(|update| {
+            let mid = update.len() / 2;
+            debug!("{:?} is in order, middle page {}", update, update[mid]);
+
+            update[mid]
+        })
+        .sum()
+}
+
+fn part2(updates: &[Update], rules: &[OrderRule]) -> Option<u32> {
+    updates
+        .iter()
+        .filter(|update| !is_sorted(update, rules))
+        .map(|update| {
+            sort(update, rules).map(|u| {
+                let mid = u.len() / 2;
+                debug!("{:?} sorts to {:?}, middle page {}", update, u, u[mid]);
+
+                u[mid]
+            })
+        })
+        .sum::<Option<_>>()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<OrderRule>, Vec<Update>);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        parser::parse_input(input).map_err(|e| match e.code {
+            ErrorKind::Verify => Error::parse_at(
+                input,
+                e.input,
+                "update has an even number of pages, so no middle page",
+            ),
+            _ => Error::from_nom(input, e),
+        })
+    }
+
+    fn part1(&self, (rules, updates): &Self::Input) -> Result<u32> {
+        Ok(part1(updates, rules))
+    }
+
+    fn part2(&self, (rules, updates): &Self::Input) -> Result<u32> {
+        part2(updates, rules).ok_or(Error::Unsolvable(String::from("could not sort")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{is_sorted, sort, Day5, OrderRule, Update};
+    use crate::{Error, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    // a rule between every pair of pages, as in the real inputs, along with an update using
+    // some of them in any order
+    fn rules_and_update() -> impl Strategy<Value = (Vec<OrderRule>, Update)> {
+        proptest::sample::subsequence((10..100).collect::<Vec<_>>(), 1..12)
+            .prop_shuffle()
+            .prop_flat_map(|pages| {
+                let rules: Vec<_> = pages
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, before)| {
+                        pages[i + 1..].iter().map(|after| OrderRule {
+                            before: *before,
+                            after: *after,
+                        })
+                    })
+                    .collect();
+
+                let len = pages.len();
+                (
+                    Just(rules),
+                    proptest::sample::subsequence(pages, 1..=len).prop_shuffle(),
+                )
+            })
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part1(&input).unwrap(), 143);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part2(&input).unwrap(), 123);
+    }
+
+    #[test]
+    fn test_parse_needs_a_middle_page() {
+        for (input, line, column) in [
+            // an empty update
+            ("1|2\n\n1,2,3\n\n3,4,5", 4, 1),
+            // an update with no middle page
+            ("1|2\n\n1,2,3\n5,3,4\n3,4", 5, 1),
+        ] {
+            let Err(Error::Parse {
+                location: Some(location),
+                ..
+            }) = Day5.parse(input)
+            else {
+                panic!("{:?} parsed", input);
+            };
+            assert_eq!((location.line, location.column), (line, column));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_is_sorted_agrees_with_sort((rules, update) in rules_and_update()) {
+            prop_assert_eq!(
+                is_sorted(&update, &rules),
+                sort(&update, &rules).as_ref() == Some(&update)
+            );
+        }
+    }
+}
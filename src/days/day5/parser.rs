@@ -1,9 +1,9 @@
-use crate::{OrderRule, Update};
+use super::{OrderRule, Update};
 
 use nom::{
     character::complete::{char, line_ending, u32},
-    combinator::{all_consuming, map},
-    multi::separated_list0,
+    combinator::{all_consuming, cut, map, verify},
+    multi::{separated_list0, separated_list1},
     sequence::{pair, separated_pair},
     Finish, IResult,
 };
@@ -18,12 +18,16 @@ fn parse_ordering_rule_list(input: &str) -> IResult<&str, Vec<OrderRule>> {
     separated_list0(line_ending, parse_order_rule)(input)
 }
 
+// an update needs an odd number of pages to have a middle one, which fails verification
 fn parse_update(input: &str) -> IResult<&str, Update> {
-    separated_list0(char(','), u32)(input)
+    verify(separated_list1(char(','), u32), |pages: &Update| {
+        pages.len() % 2 == 1
+    })(input)
 }
 
+// every update is cut, so that an error is reported on the update that caused it
 fn parse_update_list(input: &str) -> IResult<&str, Vec<Update>> {
-    separated_list0(line_ending, parse_update)(input)
+    separated_list0(line_ending, cut(parse_update))(input)
 }
 
 pub(super) fn parse_input(
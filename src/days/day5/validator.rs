@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    let mut lines = content.lines();
+
+    for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+        let Some((before, after)) = line.split_once('|') else {
+            violations.at(line, "expected an ordering rule like 47|53");
+            continue;
+        };
+
+        let before = violations.number::<u32>(before, "page");
+        let after = violations.number::<u32>(after, "page");
+        if before.is_some() && before == after {
+            violations.at(line, "a page can't be ordered before itself");
+        }
+    }
+
+    let mut updates = 0;
+    for line in lines {
+        updates += 1;
+
+        let pages: Vec<_> = line.split(',').collect();
+        let mut seen = HashSet::new();
+        for page in &pages {
+            if let Some(number) = violations.number::<u32>(page, "page") {
+                if !seen.insert(number) {
+                    violations.at(page, format!("page {} appears twice", number));
+                }
+            }
+        }
+
+        if pages.len() % 2 == 0 {
+            violations.at(
+                line,
+                format!("update has {} pages, so no middle page", pages.len()),
+            );
+        }
+    }
+
+    if updates == 0 {
+        violations.whole("no updates after the ordering rules and a blank line");
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("47|53\n53|53\n12\n\n75,47\n47,x,47\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "a page can't be ordered before itself at line 2, column 1",
+                "expected an ordering rule like 47|53 at line 3, column 1",
+                "update has 2 pages, so no middle page at line 5, column 1",
+                "invalid page \"x\" at line 6, column 4",
+                "page 47 appears twice at line 6, column 6",
+            ]
+        );
+    }
+}
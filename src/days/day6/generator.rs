@@ -0,0 +1,37 @@
+use super::{run_path, Point};
+use crate::rng::Rng;
+use crate::Grid;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 130 * scale;
+    let obstacles = Grid::from_fn(size, size, |_, _| rng.chance(0.05));
+
+    // most starting points lead off the map quickly, so try a few and keep the longest patrol,
+    // skipping any where the guard never leaves
+    let mut best = None;
+    while best.is_none() {
+        for _ in 0..20 {
+            let (x, y) = (rng.index(size), rng.index(size));
+            let guard = Point::new(x as i32, y as i32);
+
+            if obstacles[(x, y)] {
+                continue;
+            }
+
+            let Some(path) = run_path(guard, &obstacles) else {
+                continue;
+            };
+
+            let length = path.len();
+            if best.is_none_or(|(best_length, _)| length > best_length) {
+                best = Some((length, (x, y)));
+            }
+        }
+    }
+
+    let (_, guard) = best.unwrap();
+    let mut map = obstacles.map(|obstacle| if *obstacle { '#' } else { '.' });
+    map[guard] = '^';
+
+    map.to_string()
+}
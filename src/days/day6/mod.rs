@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+
+use log::debug;
+
+use crate::budget::{self, Token};
+use crate::geometry::{Direction, Vec2};
+use crate::parallel;
+use crate::render::{Canvas, Cell, Color};
+use crate::{Error, Grid, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+type Point = Vec2<i32>;
+
+fn parse_input(input: &str) -> Result<(Point, Grid<bool>)> {
+    let grid = Grid::parse(input, Some)?;
+
+    let ((x, y), _) = grid
+        .iter()
+        .find(|(_, cell)| **cell == '^')
+        .ok_or(Error::parse("no guard found"))?;
+
+    let too_large = |_| Error::parse("map is too large");
+    let guard = Point::new(
+        x.try_into().map_err(too_large)?,
+        y.try_into().map_err(too_large)?,
+    );
+
+    Ok((guard, grid.map(|cell| *cell == '#')))
+}
+
+fn is_obstacle(obstacles: &Grid<bool>, point: Point) -> bool {
+    obstacles.get(point.x, point.y) == Some(&true)
+}
+
+// returns None if the guard never leaves the map
+fn run_path(mut guard: Point, obstacles: &Grid<bool>) -> Option<HashSet<Point>> {
+    let mut visited = HashSet::new();
+    let mut heading = Direction::Up;
+
+    // a patrol that leaves the map can't be in the same place facing the same way twice, so
+    // it takes at most one step or turn for every position and heading
+    let max_steps = 4 * obstacles.width() * obstacles.height();
+
+    for _ in 0..=max_steps {
+        if !obstacles.in_bounds(guard.x, guard.y) {
+            return Some(visited);
+        }
+
+        visited.insert(guard);
+
+        let next = guard.step(heading);
+
+        if is_obstacle(obstacles, next) {
+            heading = heading.turn_right();
+        } else {
+            guard = next;
+        }
+    }
+
+    None
+}
+
+// how many steps the guard takes between checks of the budget
+const CHECK_INTERVAL: usize = 1 << 12;
+
+fn does_guard_loop(
+    mut guard: Point,
+    obstacles: &Grid<bool>,
+    extra_obstacle: Option<Point>,
+    token: &Token,
+) -> Result<bool> {
+    let mut visited = HashSet::new();
+    let mut heading = Direction::Up;
+
+    while obstacles.in_bounds(guard.x, guard.y) {
+        let state = (guard, heading);
+
+        if visited.contains(&state) {
+            return Ok(true);
+        }
+
+        visited.insert(state);
+        if visited.len().is_multiple_of(CHECK_INTERVAL) {
+            token.check()?;
+        }
+
+        let next = guard.step(heading);
+
+        if Some(next) == extra_obstacle || is_obstacle(obstacles, next) {
+            heading = heading.turn_right();
+        } else {
+            guard = next;
+        }
+    }
+
+    Ok(false)
+}
+
+fn part1(guard: Point, obstacles: &Grid<bool>) -> Option<usize> {
+    let walk = run_path(guard, obstacles)?;
+    debug!("the guard visits {} positions before leaving", walk.len());
+
+    Some(walk.len())
+}
+
+// positions on the guard's path where a new obstacle would trap them in a loop, checking the
+// budget before each one is tried and while the guard walks
+fn loop_obstacles(
+    guard: Point,
+    obstacles: &Grid<bool>,
+    visited: &HashSet<Point>,
+    token: &Token,
+) -> Result<Vec<Point>> {
+    let candidates: Vec<_> = visited.iter().filter(|p| **p != guard).collect();
+
+    let loops = parallel::try_map(&candidates, |p| {
+        token.check()?;
+
+        let loops = does_guard_loop(guard, obstacles, Some(**p), token)?;
+        if loops {
+            debug!("an obstacle at {} traps the guard in a loop", p);
+        }
+
+        Ok(loops.then_some(**p))
+    })?;
+
+    Ok(loops.into_iter().flatten().collect())
+}
+
+fn part2(guard: Point, obstacles: &Grid<bool>) -> Result<Option<usize>> {
+    let Some(visited_points) = run_path(guard, obstacles) else {
+        return Ok(None);
+    };
+
+    let token = budget::current();
+    Ok(Some(
+        loop_obstacles(guard, obstacles, &visited_points, &token)?.len(),
+    ))
+}
+
+fn render(guard: Point, obstacles: &Grid<bool>) -> Canvas {
+    let mut canvas = Canvas::from_grid(obstacles, |obstacle| match obstacle {
+        true => Cell::new('#', Color::GRAY),
+        false => Cell::new('.', Color::DIM),
+    });
+
+    // a guard stuck in a loop has no path to show
+    if let Some(visited) = run_path(guard, obstacles) {
+        for p in &visited {
+            canvas.set(p.x, p.y, Cell::new('X', Color::YELLOW));
+        }
+
+        let loop_obstacles = loop_obstacles(guard, obstacles, &visited, &Token::unlimited())
+            .expect("an unlimited budget can't be exceeded");
+        for p in loop_obstacles {
+            canvas.set(p.x, p.y, Cell::new('O', Color::WHITE).on(Color::RED));
+        }
+    }
+
+    canvas.set(guard.x, guard.y, Cell::new('^', Color::GREEN));
+    canvas
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Point, Grid<bool>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (guard, obstacles): &Self::Input) -> Result<usize> {
+        part1(*guard, obstacles).ok_or(Error::Unsolvable(String::from(
+            "the guard never leaves the map",
+        )))
+    }
+
+    fn part2(&self, (guard, obstacles): &Self::Input) -> Result<usize> {
+        part2(*guard, obstacles)?.ok_or(Error::Unsolvable(String::from(
+            "the guard never leaves the map",
+        )))
+    }
+
+    fn render(&self, (guard, obstacles): &Self::Input) -> Option<Canvas> {
+        Some(render(*guard, obstacles))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{does_guard_loop, run_path, Day6, Point};
+    use crate::budget::Token;
+    use crate::{Grid, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    // small maps with a guard on a free cell
+    fn map() -> impl Strategy<Value = (Point, Grid<bool>)> {
+        (1..8usize, 1..8usize)
+            .prop_flat_map(|(width, height)| {
+                (
+                    Just(width),
+                    proptest::collection::vec(proptest::bool::weighted(0.25), width * height),
+                    0..width,
+                    0..height,
+                )
+            })
+            .prop_map(|(width, mut cells, x, y)| {
+                cells[y * width + x] = false;
+
+                let height = cells.len() / width;
+                let obstacles = Grid::new(width, height, cells).unwrap();
+                (Point::new(x as i32, y as i32), obstacles)
+            })
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day6.parse(EXAMPLE).unwrap();
+        assert_eq!(Day6.part1(&input).unwrap(), 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day6.parse(EXAMPLE).unwrap();
+        assert_eq!(Day6.part2(&input).unwrap(), 6);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_run_path_agrees_with_does_guard_loop((guard, obstacles) in map()) {
+            prop_assert_eq!(
+                run_path(guard, &obstacles).is_none(),
+                does_guard_loop(guard, &obstacles, None, &Token::unlimited()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    violations.grid(|c| ".#^".contains(c), "'.', '#' or '^'");
+
+    let mut guards = input.match_indices('^').map(|(index, _)| &input[index..]);
+    match guards.next() {
+        Some(_) => {
+            for guard in guards {
+                violations.at(guard, "there is already a guard");
+            }
+        }
+        None => violations.whole("there is no guard"),
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("..#\n^.v\n.^.\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "unexpected character 'v', expected '.', '#' or '^' at line 2, column 3",
+                "there is already a guard at line 3, column 2",
+            ]
+        );
+
+        let found: Vec<_> = validate("..#\n...\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(found, ["there is no guard"]);
+    }
+}
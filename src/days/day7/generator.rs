@@ -0,0 +1,49 @@
+use super::concat_num;
+use crate::rng::Rng;
+
+fn equation(rng: &mut Rng) -> Option<String> {
+    let count = rng.range(3..=12) as usize;
+    let numbers: Vec<u64> = (0..count)
+        .map(|_| match rng.below(3) {
+            0 => rng.range(1..=9),
+            1 => rng.range(10..=99),
+            _ => rng.range(100..=999),
+        })
+        .collect();
+
+    let mut value = numbers[0];
+    for n in &numbers[1..] {
+        value = match rng.below(3) {
+            0 => value.checked_add(*n)?,
+            1 => value.checked_mul(*n)?,
+            _ => concat_num(value, *n)?,
+        };
+    }
+
+    // keep test values in the range real inputs use
+    if value > 1_000_000_000_000_000 {
+        return None;
+    }
+
+    // some equations can't be made true
+    if rng.chance(0.4) {
+        value += rng.range(1..=100);
+    }
+
+    let numbers: Vec<_> = numbers.iter().map(u64::to_string).collect();
+    Some(format!("{}: {}\n", value, numbers.join(" ")))
+}
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+
+    let mut count = 0;
+    while count < 850 * scale {
+        if let Some(line) = equation(rng) {
+            out.push_str(&line);
+            count += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,282 @@
+use std::fmt::{self, Display};
+
+use log::{debug, log_enabled, Level};
+
+use crate::budget::{self, Token};
+use crate::parallel;
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+pub struct Equation {
+    result: u64,
+    terms: Vec<u64>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Equation>> {
+    input
+        .lines()
+        .map(|line| {
+            let (res, terms) = line.split_once(": ").ok_or_else(|| {
+                Error::parse_at(input, line, "invalid input format: missing colon")
+            })?;
+
+            let result = res.parse().map_err(|e| {
+                Error::parse_at(input, res, format!("could not parse result: {}", e))
+            })?;
+
+            let terms = terms
+                .split(' ')
+                .map(|x| {
+                    x.parse().map_err(|e| {
+                        Error::parse_at(input, x, format!("could not parse term: {}", e))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Equation { result, terms })
+        })
+        .collect()
+}
+
+fn concat_num(current: u64, next: u64) -> Option<u64> {
+    format!("{}{}", current, next).parse().ok()
+}
+
+// how many partial results to try between checks of the budget
+const CHECK_INTERVAL: usize = 1 << 12;
+
+fn is_solvable(equation: &Equation, enable_concat_operator: bool, token: &Token) -> Result<bool> {
+    let mut stack = Vec::new();
+
+    if let Some((first, rem)) = equation.terms.split_first() {
+        stack.push((*first, rem));
+    }
+
+    let mut tried = 0usize;
+    while let Some((current, rem)) = stack.pop() {
+        if rem.is_empty() && current == equation.result {
+            return Ok(true);
+        }
+
+        tried += 1;
+        if tried.is_multiple_of(CHECK_INTERVAL) {
+            token.check()?;
+        }
+
+        if let Some((next, r)) = rem.split_first() {
+            if let Some(n) = current.checked_add(*next) {
+                stack.push((n, r));
+            }
+
+            if let Some(n) = current.checked_mul(*next) {
+                stack.push((n, r));
+            }
+
+            if enable_concat_operator {
+                if let Some(c) = concat_num(current, *next) {
+                    stack.push((c, r));
+                }
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+    Add,
+    Mul,
+    Concat,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Mul => write!(f, "*"),
+            Operator::Concat => write!(f, "||"),
+        }
+    }
+}
+
+// finds operators that make the equation true, which is slower than is_solvable so is only used
+// for logging. The budget is checked on every step of the search
+fn find_operators(
+    equation: &Equation,
+    enable_concat_operator: bool,
+    token: &Token,
+) -> Result<Option<Vec<Operator>>> {
+    fn search(
+        current: u64,
+        rem: &[u64],
+        result: u64,
+        operators: &mut Vec<Operator>,
+        enable_concat_operator: bool,
+        token: &Token,
+    ) -> Result<bool> {
+        token.check()?;
+
+        let Some((next, r)) = rem.split_first() else {
+            return Ok(current == result);
+        };
+
+        let mut candidates = vec![
+            (Operator::Add, current.checked_add(*next)),
+            (Operator::Mul, current.checked_mul(*next)),
+        ];
+        if enable_concat_operator {
+            candidates.push((Operator::Concat, concat_num(current, *next)));
+        }
+
+        for (operator, value) in candidates {
+            let Some(value) = value else {
+                continue;
+            };
+
+            operators.push(operator);
+            if search(value, r, result, operators, enable_concat_operator, token)? {
+                return Ok(true);
+            }
+            operators.pop();
+        }
+
+        Ok(false)
+    }
+
+    let Some((first, rem)) = equation.terms.split_first() else {
+        return Ok(None);
+    };
+    let mut operators = Vec::new();
+
+    let found = search(
+        *first,
+        rem,
+        equation.result,
+        &mut operators,
+        enable_concat_operator,
+        token,
+    )?;
+
+    Ok(found.then_some(operators))
+}
+
+fn log_equation(equation: &Equation, enable_concat_operator: bool, token: &Token) -> Result<()> {
+    match find_operators(equation, enable_concat_operator, token)? {
+        Some(operators) => {
+            let mut expression = equation.terms[0].to_string();
+            for (operator, term) in operators.iter().zip(&equation.terms[1..]) {
+                expression.push_str(&format!(" {} {}", operator, term));
+            }
+
+            debug!("{} = {}", equation.result, expression);
+        }
+        None => debug!(
+            "{} can't be made from {:?}",
+            equation.result, equation.terms
+        ),
+    }
+
+    Ok(())
+}
+
+fn calibration_total(equations: &[Equation], enable_concat_operator: bool) -> Result<u64> {
+    let token = budget::current();
+
+    if log_enabled!(Level::Debug) {
+        for equation in equations {
+            log_equation(equation, enable_concat_operator, &token)?;
+        }
+    }
+
+    let results = parallel::try_map(equations, |equation| {
+        Ok(is_solvable(equation, enable_concat_operator, &token)?.then_some(equation.result))
+    })?;
+
+    results
+        .into_iter()
+        .flatten()
+        .try_fold(0u64, |total, result| total.checked_add(result))
+        .ok_or(Error::Overflow(String::from(
+            "calibration total does not fit in a u64",
+        )))
+}
+
+fn part1(equations: &[Equation]) -> Result<u64> {
+    calibration_total(equations, false)
+}
+
+fn part2(equations: &[Equation]) -> Result<u64> {
+    calibration_total(equations, true)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input.trim())
+    }
+
+    fn part1(&self, equations: &Self::Input) -> Result<u64> {
+        part1(equations)
+    }
+
+    fn part2(&self, equations: &Self::Input) -> Result<u64> {
+        part2(equations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{find_operators, Day7};
+    use crate::budget::{self, Budget, Token};
+    use crate::{Error, Solution};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day7.parse(EXAMPLE).unwrap();
+        assert_eq!(Day7.part1(&input).unwrap(), 3749);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day7.parse(EXAMPLE).unwrap();
+        assert_eq!(Day7.part2(&input).unwrap(), 11387);
+    }
+
+    #[test]
+    fn test_find_operators() {
+        let equations = Day7.parse("3267: 81 40 27\n").unwrap();
+
+        let operators = find_operators(&equations[0], false, &Token::unlimited())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            operators
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["+", "*"]
+        );
+
+        let budget = Budget {
+            timeout: Some(Duration::ZERO),
+            max_mem: None,
+        };
+        let result = budget.run(|| {
+            std::thread::sleep(Duration::from_millis(1));
+            find_operators(&equations[0], false, &budget::current())
+        });
+        assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+    }
+}
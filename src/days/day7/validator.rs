@@ -0,0 +1,48 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    for line in content.lines() {
+        let Some((result, terms)) = line.split_once(": ") else {
+            violations.at(line, "expected an equation like 190: 10 19");
+            continue;
+        };
+
+        violations.number::<u64>(result, "test value");
+        for term in terms.split(' ') {
+            violations.number::<u64>(term, "number");
+        }
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("190: 10 19\n3267 81 40\nx: 1\n10: 1  2\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "expected an equation like 190: 10 19 at line 2, column 1",
+                "invalid test value \"x\" at line 3, column 1",
+                "missing number at line 4, column 7",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the input is empty"]);
+    }
+}
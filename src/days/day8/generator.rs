@@ -0,0 +1,25 @@
+use crate::rng::Rng;
+use crate::Grid;
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 50 * scale;
+    let mut map = Grid::from_fn(size, size, |_, _| '.');
+
+    let frequencies = (40 * scale).min(FREQUENCIES.len());
+    for frequency in &FREQUENCIES[..frequencies] {
+        let mut placed = 0;
+        let antennas = rng.range(3..=4) as usize * scale;
+
+        while placed < antennas {
+            let cell = &mut map[(rng.index(size), rng.index(size))];
+            if *cell == '.' {
+                *cell = *frequency as char;
+                placed += 1;
+            }
+        }
+    }
+
+    map.to_string()
+}
@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+
+use log::{debug, trace};
+
+use crate::geometry::Vec2;
+use crate::render::{Canvas, Cell, Color};
+use crate::{Error, Grid, Result, Solution};
+
+pub(crate) mod generator;
+pub(crate) mod validator;
+
+type Antennas = HashMap<char, HashSet<Vec2<i32>>>;
+
+fn parse_input(input: &str) -> Result<(Antennas, Grid<char>)> {
+    let grid = Grid::parse(input, Some)?;
+    if grid.is_empty() {
+        return Err(Error::parse("empty map"));
+    }
+
+    let too_large = |_| Error::parse("map is too large");
+
+    let mut antennas = HashMap::new();
+
+    for ((column_index, row_index), cell) in grid.iter() {
+        if *cell == '.' {
+            continue;
+        }
+
+        let x = column_index.try_into().map_err(too_large)?;
+        let y = row_index.try_into().map_err(too_large)?;
+
+        antennas
+            .entry(*cell)
+            .or_insert(HashSet::new())
+            .insert(Vec2::new(x, y));
+    }
+
+    for (frequency, positions) in &antennas {
+        debug!("frequency {:?} has {} antennas", frequency, positions.len());
+    }
+
+    Ok((antennas, grid))
+}
+
+fn antinodes(antennas: &Antennas, grid: &Grid<char>) -> HashSet<Vec2<i32>> {
+    let mut positions = HashSet::new();
+
+    for (frequency, v) in antennas {
+        for a1 in v {
+            for a2 in v {
+                if a1 == a2 {
+                    continue;
+                }
+
+                let delta = *a2 - *a1;
+                trace!(
+                    "{:?} antennas at {} and {} put antinodes at {} and {}",
+                    frequency,
+                    a1,
+                    a2,
+                    *a1 - delta,
+                    *a2 + delta
+                );
+
+                positions.insert(*a1 - delta);
+                positions.insert(*a2 + delta);
+            }
+        }
+    }
+
+    positions.retain(|p| grid.in_bounds(p.x, p.y));
+    positions
+}
+
+fn walk_diff(a1: Vec2<i32>, a2: Vec2<i32>, delta: Vec2<i32>, grid: &Grid<char>) -> Vec<Vec2<i32>> {
+    let mut points = Vec::new();
+
+    let mut p = a1 - delta;
+    while grid.in_bounds(p.x, p.y) {
+        points.push(p);
+
+        p -= delta;
+    }
+
+    p = a2 + delta;
+    while grid.in_bounds(p.x, p.y) {
+        points.push(p);
+        p += delta;
+    }
+
+    points
+}
+
+// antinodes including those from resonant harmonics
+fn resonant_antinodes(antennas: &Antennas, grid: &Grid<char>) -> HashSet<Vec2<i32>> {
+    let mut positions = HashSet::new();
+
+    for (frequency, v) in antennas {
+        if v.len() > 1 {
+            positions.extend(v);
+        }
+
+        for a1 in v {
+            for a2 in v {
+                if a1 == a2 {
+                    continue;
+                }
+
+                let delta = *a2 - *a1;
+                let pos = walk_diff(*a1, *a2, delta, grid);
+                trace!(
+                    "{:?} antennas at {} and {} resonate at {:?}",
+                    frequency,
+                    a1,
+                    a2,
+                    pos
+                );
+
+                positions.extend(pos);
+            }
+        }
+    }
+
+    positions.retain(|p| grid.in_bounds(p.x, p.y));
+    positions
+}
+
+fn part1(antennas: &Antennas, grid: &Grid<char>) -> usize {
+    antinodes(antennas, grid).len()
+}
+
+fn part2(antennas: &Antennas, grid: &Grid<char>) -> usize {
+    resonant_antinodes(antennas, grid).len()
+}
+
+fn render(antennas: &Antennas, grid: &Grid<char>) -> Canvas {
+    let mut frequencies: Vec<_> = antennas.keys().collect();
+    frequencies.sort();
+
+    let color = |cell: char| match frequencies.binary_search(&&cell) {
+        Ok(index) => Color::category(index),
+        Err(_) => Color::DIM,
+    };
+
+    let mut canvas = Canvas::from_grid(grid, |cell| Cell::new(*cell, color(*cell)));
+
+    for (antinodes, background) in [
+        (resonant_antinodes(antennas, grid), Color::BLUE.darken(0.5)),
+        (antinodes(antennas, grid), Color::BLUE),
+    ] {
+        for p in antinodes {
+            let cell = match grid[(p.x as usize, p.y as usize)] {
+                '.' => Cell::new('#', Color::WHITE),
+                antenna => Cell::new(antenna, color(antenna)),
+            };
+
+            canvas.set(p.x, p.y, cell.on(background));
+        }
+    }
+
+    canvas
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Antennas, Grid<char>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input.trim())
+    }
+
+    fn part1(&self, (antennas, grid): &Self::Input) -> Result<usize> {
+        Ok(part1(antennas, grid))
+    }
+
+    fn part2(&self, (antennas, grid): &Self::Input) -> Result<usize> {
+        Ok(part2(antennas, grid))
+    }
+
+    fn render(&self, (antennas, grid): &Self::Input) -> Option<Canvas> {
+        Some(render(antennas, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day8;
+    use crate::Solution;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part1(&input).unwrap(), 14);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part2(&input).unwrap(), 34);
+    }
+}
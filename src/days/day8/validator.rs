@@ -0,0 +1,35 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    violations.grid(
+        |c| c == '.' || c.is_ascii_alphanumeric(),
+        "'.' or an antenna's letter or digit",
+    );
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("..a.\n.#..\n...\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "unexpected character '#', expected '.' or an antenna's letter or digit at line 2, column 2",
+                "row has 3 columns, expected 4 at line 3, column 1",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the map is empty"]);
+    }
+}
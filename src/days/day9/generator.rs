@@ -0,0 +1,18 @@
+use crate::rng::Rng;
+
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let files = 10000 * scale;
+    let mut out = String::with_capacity(2 * files);
+
+    for file in 0..files {
+        out.push(char::from_digit(rng.range(1..=9) as u32, 10).unwrap());
+
+        // the map always ends with a file
+        if file + 1 < files {
+            out.push(char::from_digit(rng.range(0..=9) as u32, 10).unwrap());
+        }
+    }
+
+    out.push('\n');
+    out
+}
@@ -0,0 +1,50 @@
+use log::debug;
+
+use crate::budget;
+use crate::{Error, Result, Solution};
+
+pub(crate) mod generator;
+mod part1;
+mod part2;
+pub(crate) mod validator;
+
+fn parse_disk(input: &str) -> Result<Vec<u8>> {
+    let mut disk = Vec::new();
+    let trimmed = input.trim();
+
+    for (index, c) in trimmed.char_indices() {
+        let d = c.to_digit(10).ok_or_else(|| {
+            Error::parse_at(input, &trimmed[index..], format!("invalid size {:?}", c))
+        })?;
+
+        disk.push(d as u8);
+    }
+
+    debug!(
+        "{} files taking {} blocks",
+        disk.len().div_ceil(2),
+        disk.iter().map(|size| *size as usize).sum::<usize>()
+    );
+
+    Ok(disk)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u8>;
+    type Output1 = usize;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_disk(input)
+    }
+
+    fn part1(&self, disk: &Self::Input) -> Result<usize> {
+        Ok(part1::part1(disk))
+    }
+
+    fn part2(&self, disk: &Self::Input) -> Result<i64> {
+        part2::part2(disk, &budget::current())
+    }
+}
@@ -3,7 +3,7 @@ enum Block {
     Empty,
 }
 
-fn parse_blocks(disk: &Vec<u8>) -> Vec<Block> {
+fn parse_blocks(disk: &[u8]) -> Vec<Block> {
     let mut blocks = Vec::new();
 
     let mut is_file = true;
@@ -27,7 +27,7 @@ fn parse_blocks(disk: &Vec<u8>) -> Vec<Block> {
     blocks
 }
 
-pub(super) fn part1(disk: &Vec<u8>) -> usize {
+pub(super) fn part1(disk: &[u8]) -> usize {
     let mut blocks = parse_blocks(disk);
 
     let mut front = 0;
@@ -35,7 +35,10 @@ pub(super) fn part1(disk: &Vec<u8>) -> usize {
         front += 1;
     }
 
-    let mut back = blocks.len() - 1;
+    // an empty disk has nothing to move, and a checksum of 0
+    let Some(mut back) = blocks.len().checked_sub(1) else {
+        return 0;
+    };
     while 0 < back && !matches!(blocks[back], Block::File(_)) {
         back -= 1;
     }
@@ -63,3 +66,21 @@ pub(super) fn part1(disk: &Vec<u8>) -> usize {
 
     checksum
 }
+
+#[cfg(test)]
+mod tests {
+    use super::part1;
+    use crate::days::day9::parse_disk;
+
+    #[test]
+    fn test_part1() {
+        let disk = parse_disk(include_str!("example.txt")).unwrap();
+        assert_eq!(part1(&disk), 1928);
+    }
+
+    #[test]
+    fn test_part1_empty_disk() {
+        assert_eq!(part1(&parse_disk("").unwrap()), 0);
+        assert_eq!(part1(&parse_disk("0").unwrap()), 0);
+    }
+}
@@ -1,3 +1,8 @@
+use log::trace;
+
+use crate::budget::Token;
+use crate::{Error, Result};
+
 struct File {
     id: i32,
     size: u32,
@@ -31,7 +36,7 @@ impl Entry {
     }
 }
 
-fn parse_entries(disk: &Vec<u8>) -> Vec<Entry> {
+fn parse_entries(disk: &[u8]) -> Vec<Entry> {
     let mut entries = Vec::new();
 
     let mut is_file = true;
@@ -103,23 +108,29 @@ fn create_block_list(entries: &[Entry]) -> Vec<i32> {
     blocks
 }
 
-fn calculate_checksum(blocks: &[i32]) -> i64 {
-    let mut checksum = 0;
+fn calculate_checksum(blocks: &[i32]) -> Result<i64> {
+    let overflow = || Error::Overflow(String::from("checksum does not fit in an i64"));
+    let mut checksum: i64 = 0;
 
     for (index, block) in blocks.iter().enumerate() {
-        let index64: i64 = index.try_into().expect("index overflowed");
+        let index64: i64 = index.try_into().map_err(|_| overflow())?;
 
         if *block >= 0 {
-            checksum += (*block as i64) * index64;
+            checksum = (*block as i64)
+                .checked_mul(index64)
+                .and_then(|x| checksum.checked_add(x))
+                .ok_or_else(overflow)?;
         }
     }
 
-    checksum
+    Ok(checksum)
 }
 
-pub(crate) fn part2(disk: &Vec<u8>) -> i64 {
+pub(crate) fn part2(disk: &[u8], token: &Token) -> Result<i64> {
     let mut entries = parse_entries(disk);
-    let mut back = entries.len() - 1;
+    let Some(mut back) = entries.len().checked_sub(1) else {
+        return Ok(0);
+    };
 
     while back > 0 {
         // if empty space then move left
@@ -136,6 +147,9 @@ pub(crate) fn part2(disk: &Vec<u8>) -> i64 {
             continue;
         }
 
+        // each search walks the whole disk, so check the budget before starting one
+        token.check()?;
+
         // find the left-most free-space that will fit the current item
         let mut front = 0;
         while front < back && !matches!(entries[front], Entry::Empty(size) if size >= file.size) {
@@ -144,15 +158,51 @@ pub(crate) fn part2(disk: &Vec<u8>) -> i64 {
 
         // no suitable free-space
         if front == back {
+            trace!("file {} has no space to its left", file.id);
             back -= 1;
             continue;
         }
 
+        trace!("file {} moves to entry {}", file.id, front);
         move_entry(&mut entries, front, back);
     }
 
     let blocks = create_block_list(&entries);
-    let checksum = calculate_checksum(&blocks);
 
-    checksum
+    calculate_checksum(&blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::part2;
+    use crate::budget::{self, Budget, Token};
+    use crate::days::day9::parse_disk;
+    use crate::Error;
+
+    #[test]
+    fn test_part2() {
+        let disk = parse_disk(include_str!("example.txt")).unwrap();
+        assert_eq!(part2(&disk, &Token::unlimited()).unwrap(), 2858);
+    }
+
+    #[test]
+    fn test_part2_empty_disk() {
+        let token = Token::unlimited();
+        assert_eq!(part2(&parse_disk("").unwrap(), &token).unwrap(), 0);
+        assert_eq!(part2(&parse_disk("0").unwrap(), &token).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_part2_timeout() {
+        let disk = parse_disk(&"12".repeat(20_000)).unwrap();
+        let budget = Budget {
+            timeout: Some(Duration::from_millis(1)),
+            max_mem: None,
+        };
+
+        let result = budget.run(|| part2(&disk, &budget::current()));
+        assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+    }
 }
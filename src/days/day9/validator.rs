@@ -0,0 +1,43 @@
+use crate::validate::{Violation, Violations};
+
+pub(crate) fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Violations::new(input);
+    let Some(content) = violations.content() else {
+        return violations.into_vec();
+    };
+
+    for (index, c) in content.char_indices() {
+        if c == '\n' {
+            violations.at(&content[index + 1..], "the disk map is a single line");
+        } else if !c.is_ascii_digit() {
+            violations.at(&content[index..], format!("invalid size {:?}", c));
+        }
+    }
+
+    violations.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(include_str!("example.txt")).is_empty());
+
+        let found: Vec<_> = validate("12a4\n5\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "invalid size 'a' at line 1, column 3",
+                "the disk map is a single line at line 2, column 1",
+            ]
+        );
+
+        let found: Vec<_> = validate("").iter().map(ToString::to_string).collect();
+        assert_eq!(found, ["the input is empty"]);
+    }
+}
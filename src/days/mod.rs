@@ -0,0 +1,138 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub use day1::Day1;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+pub use day13::Day13;
+pub use day2::Day2;
+pub use day3::Day3;
+pub use day4::Day4;
+pub use day5::Day5;
+pub use day6::Day6;
+pub use day7::Day7;
+pub use day8::Day8;
+pub use day9::Day9;
+
+use crate::rng::Rng;
+use crate::solution::DynSolution;
+use crate::validate::Violation;
+
+/// Every day that has a solution, in order.
+pub const DAYS: [u32; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+
+pub fn get(day: u32) -> Option<&'static dyn DynSolution> {
+    match day {
+        1 => Some(&Day1),
+        2 => Some(&Day2),
+        3 => Some(&Day3),
+        4 => Some(&Day4),
+        5 => Some(&Day5),
+        6 => Some(&Day6),
+        7 => Some(&Day7),
+        8 => Some(&Day8),
+        9 => Some(&Day9),
+        10 => Some(&Day10),
+        11 => Some(&Day11),
+        12 => Some(&Day12),
+        13 => Some(&Day13),
+        _ => None,
+    }
+}
+
+/// Generates a random but valid input for `day`, the same every time for a given `seed`.
+///
+/// At scale 1 inputs are about the size of real puzzle inputs. Larger scales multiply the number
+/// of lines, and for maps the width as well.
+pub fn generate(day: u32, seed: u64, scale: usize) -> Option<String> {
+    let generate = match day {
+        1 => day1::generator::generate,
+        2 => day2::generator::generate,
+        3 => day3::generator::generate,
+        4 => day4::generator::generate,
+        5 => day5::generator::generate,
+        6 => day6::generator::generate,
+        7 => day7::generator::generate,
+        8 => day8::generator::generate,
+        9 => day9::generator::generate,
+        10 => day10::generator::generate,
+        11 => day11::generator::generate,
+        12 => day12::generator::generate,
+        13 => day13::generator::generate,
+        _ => return None,
+    };
+
+    Some(generate(&mut Rng::new(seed), scale))
+}
+
+/// Checks that an input for `day` has the structure the puzzle describes, returning every
+/// violation rather than stopping at the first like parsing does.
+pub fn validate(day: u32, input: &str) -> Option<Vec<Violation>> {
+    let validate = match day {
+        1 => day1::validator::validate,
+        2 => day2::validator::validate,
+        3 => day3::validator::validate,
+        4 => day4::validator::validate,
+        5 => day5::validator::validate,
+        6 => day6::validator::validate,
+        7 => day7::validator::validate,
+        8 => day8::validator::validate,
+        9 => day9::validator::validate,
+        10 => day10::validator::validate,
+        11 => day11::validator::validate,
+        12 => day12::validator::validate,
+        13 => day13::validator::validate,
+        _ => return None,
+    };
+
+    Some(validate(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, get, validate, DAYS};
+    use crate::{Error, Part};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for day in DAYS {
+            let input = generate(day, 1, 1).unwrap();
+            assert_eq!(generate(day, 1, 1).unwrap(), input, "day {}", day);
+
+            let solution = get(day).unwrap();
+            let parsed = solution
+                .parse(&input)
+                .unwrap_or_else(|e| panic!("day {}: {}", day, e));
+
+            for part in Part::ALL {
+                match solution.solve(parsed.as_ref(), part) {
+                    // a day just scaffolded by `aoc new`
+                    Err(Error::Unsolvable(message)) if message.ends_with("is not solved yet") => {}
+                    Err(e) => panic!("day {} part {}: {}", day, part, e),
+                    Ok(_) => {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_are_valid() {
+        for day in DAYS {
+            let input = generate(day, 1, 1).unwrap();
+            let violations = validate(day, &input).unwrap();
+            assert!(violations.is_empty(), "day {}: {:?}", day, violations);
+        }
+    }
+}
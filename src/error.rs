@@ -0,0 +1,227 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Where in the puzzle input a parse error occurred.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The full line containing the error.
+    pub snippet: String,
+}
+
+impl Location {
+    /// Locates the byte offset `offset` within `input`.
+    pub fn at_offset(input: &str, offset: usize) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: input[line_start..].lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    /// Locates the start of `at`, which must be a slice of `input`.
+    ///
+    /// This is how the remaining input reported by nom, or a token from `split`, is turned back
+    /// into a position. Slices from elsewhere are reported at the end of the input.
+    pub fn of_slice(input: &str, at: &str) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .unwrap_or(input.len());
+
+        Self::at_offset(input, offset)
+    }
+
+    /// The line, with a caret under the column, for showing below an error message.
+    pub fn excerpt(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        format!(
+            "{} |\n{} | {}\n{} | {}^",
+            gutter,
+            self.line,
+            self.snippet,
+            gutter,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Error {
+    /// The puzzle input could not be read.
+    Io {
+        path: PathBuf,
+        source: Arc<io::Error>,
+    },
+    /// The puzzle input was malformed.
+    Parse {
+        message: String,
+        location: Option<Location>,
+    },
+    /// The puzzle input was well-formed but has no answer.
+    Unsolvable(String),
+    /// An intermediate value did not fit in its integer type.
+    Overflow(String),
+    /// A request to the Advent of Code website failed.
+    Http(String),
+    /// A git command failed, or git couldn't be run.
+    Git(String),
+    /// A solver ran for longer, or used more memory, than it was allowed.
+    BudgetExceeded(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source: Arc::new(source),
+        }
+    }
+
+    /// A parse error that can't be pinned to a position in the input.
+    pub fn parse(message: impl Into<String>) -> Self {
+        Error::Parse {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// A parse error at the start of `at`, which must be a slice of `input`.
+    pub fn parse_at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        Error::Parse {
+            message: message.into(),
+            location: Some(Location::of_slice(input, at)),
+        }
+    }
+
+    pub fn from_nom(input: &str, e: nom::error::Error<&str>) -> Self {
+        Self::parse_at(
+            input,
+            e.input,
+            format!("unexpected input (expected {})", e.code.description()),
+        )
+    }
+
+    /// A short, stable name for the kind of error, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Io { .. } => "io",
+            Error::Parse { .. } => "parse",
+            Error::Unsolvable(_) => "unsolvable",
+            Error::Overflow(_) => "overflow",
+            Error::Http(_) => "http",
+            Error::Git(_) => "git",
+            Error::BudgetExceeded(_) => "budget",
+        }
+    }
+
+    /// The process exit code used when a binary fails with this error.
+    ///
+    /// 2 is left for command line usage errors, which clap reports itself.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Io { .. } => 3,
+            Error::Parse { .. } => 4,
+            Error::Unsolvable(_) => 5,
+            Error::Overflow(_) => 6,
+            Error::Http(_) => 7,
+            Error::Git(_) => 8,
+            Error::BudgetExceeded(_) => 9,
+        }
+    }
+
+    /// The error message, followed by the offending line with a caret under the error position
+    /// when one is known.
+    pub fn diagnostic(&self) -> String {
+        let mut out = format!("error: {}", self);
+
+        if let Error::Parse {
+            location: Some(location),
+            ..
+        } = self
+        {
+            out.push('\n');
+            out.push_str(&location.excerpt());
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            Error::Parse {
+                message,
+                location: Some(location),
+            } => write!(
+                f,
+                "could not parse input: {} at line {}, column {}",
+                message, location.line, location.column
+            ),
+            Error::Parse {
+                message,
+                location: None,
+            } => write!(f, "could not parse input: {}", message),
+            Error::Unsolvable(msg) => write!(f, "could not solve: {}", msg),
+            Error::Overflow(msg) => write!(f, "arithmetic overflow: {}", msg),
+            Error::Http(msg) => write!(f, "request failed: {}", msg),
+            Error::Git(msg) => write!(f, "git failed: {}", msg),
+            Error::BudgetExceeded(msg) => write!(f, "budget exceeded: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Location};
+
+    #[test]
+    fn test_location() {
+        let input = "abc\ndéf\nghi";
+        let at = &input[input.find('f').unwrap()..];
+
+        let location = Location::of_slice(input, at);
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 3);
+        assert_eq!(location.snippet, "déf");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "1 2\n3 x\n";
+        let e = Error::parse_at(input, &input[6..], "invalid digit");
+
+        assert_eq!(
+            e.diagnostic(),
+            "error: could not parse input: invalid digit at line 2, column 3\n  |\n2 | 3 x\n  |   ^"
+        );
+    }
+}
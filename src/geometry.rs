@@ -0,0 +1,337 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+
+/// Primitive integers usable as [`Vec2`] coordinates.
+pub trait Num:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+/// Signed [`Num`]s, which are the only ones that can step in every [`Direction`].
+pub trait Signed: Num + Neg<Output = Self> {}
+
+macro_rules! impl_num {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(impl Signed for $t {})*
+    };
+}
+
+impl_num!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+fn abs_diff<T: Num>(a: T, b: T) -> T {
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+fn gcd<T: Num>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Num> Vec2<T> {
+    pub const ZERO: Self = Vec2::new(T::ZERO, T::ZERO);
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Divides both components by their greatest common divisor, giving the smallest vector
+    /// with the same direction.
+    ///
+    /// The zero vector is returned unchanged.
+    pub fn normalized(self) -> Self {
+        let divisor = gcd(abs_diff(self.x, T::ZERO), abs_diff(self.y, T::ZERO));
+        if divisor == T::ZERO {
+            return self;
+        }
+
+        self / divisor
+    }
+}
+
+impl<T: Signed> Vec2<T> {
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// Rotates a quarter turn clockwise, with y pointing down.
+    pub fn rotate_right(self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn anticlockwise, with y pointing down.
+    pub fn rotate_left(self) -> Self {
+        Vec2::new(self.y, -self.x)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Vec2<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// One of the four orthogonal directions, with up being towards negative y.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn offset<T: Signed>(self) -> Vec2<T> {
+        match self {
+            Direction::Up => Vec2::new(T::ZERO, -T::ONE),
+            Direction::Right => Vec2::new(T::ONE, T::ZERO),
+            Direction::Down => Vec2::new(T::ZERO, T::ONE),
+            Direction::Left => Vec2::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions in clockwise order, starting from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns an eighth of a full turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns an eighth of a full turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn offset<T: Signed>(self) -> Vec2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        match self {
+            Direction8::North => Vec2::new(zero, -one),
+            Direction8::NorthEast => Vec2::new(one, -one),
+            Direction8::East => Vec2::new(one, zero),
+            Direction8::SouthEast => Vec2::new(one, one),
+            Direction8::South => Vec2::new(zero, one),
+            Direction8::SouthWest => Vec2::new(-one, one),
+            Direction8::West => Vec2::new(-one, zero),
+            Direction8::NorthWest => Vec2::new(-one, -one),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::North,
+            Direction::Right => Direction8::East,
+            Direction::Down => Direction8::South,
+            Direction::Left => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, Vec2};
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(-1, 2);
+
+        assert_eq!(a + b, Vec2::new(2, -2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(-a, Vec2::new(-3, 4));
+        assert_eq!(b * 3, Vec2::new(-3, 6));
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(Vec2::new(2u64, 9).manhattan_distance(Vec2::new(5, 1)), 11);
+    }
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(Vec2::new(6, -9).normalized(), Vec2::new(2, -3));
+        assert_eq!(Vec2::new(0, -4).normalized(), Vec2::new(0, -1));
+        assert_eq!(Vec2::new(0, 0).normalized(), Vec2::new(0, 0));
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(
+                direction.offset::<i32>().rotate_right(),
+                direction.turn_right().offset()
+            );
+            assert_eq!(
+                Direction8::from(direction).offset::<i32>(),
+                direction.offset()
+            );
+        }
+
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().offset::<i32>(), -direction.offset());
+        }
+    }
+}
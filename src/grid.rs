@@ -0,0 +1,292 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (0, -1),
+    (0, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Cells are addressed by `(x, y)` with the origin in the top left corner. Lookups accept any
+/// integer type, so coordinates that are negative or too large simply miss.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order, or `None` if the sizes don't match.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if width.checked_mul(height)? != cells.len() {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping each character with `f`.
+    ///
+    /// Fails if `f` rejects a character or if the lines are not all the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let mut row_width = 0;
+
+            for (byte_index, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    Error::parse_at(
+                        input,
+                        &line[byte_index..],
+                        format!("unexpected character {:?}", c),
+                    )
+                })?;
+
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(Error::parse_at(
+                        input,
+                        line,
+                        format!("row has {} columns, expected {}", row_width, w),
+                    ))
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn index_of<I: TryInto<usize>>(&self, x: I, y: I) -> Option<usize> {
+        let x = x.try_into().ok()?;
+        let y = y.try_into().ok()?;
+
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn in_bounds<I: TryInto<usize>>(&self, x: I, y: I) -> bool {
+        self.index_of(x, y).is_some()
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, x: I, y: I) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, x: I, y: I) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// All cells along with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero size, which only happens when there are no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x` from top to bottom, which panics like [`Grid::row`] when `x` is
+    /// out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is out of bounds for a width of {}",
+            x,
+            self.width
+        );
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells visited walking from `(x, y)` in steps of `(dx, dy)`, starting with `(x, y)` itself
+    /// and stopping at the edge of the grid.
+    ///
+    /// A step of `(1, 1)` or `(1, -1)` walks along a diagonal.
+    pub fn ray(&self, x: usize, y: usize, dx: isize, dy: isize) -> impl Iterator<Item = &T> {
+        let mut next = Some((x, y));
+
+        std::iter::from_fn(move || {
+            let (x, y) = next?;
+            let cell = self.get(x, y)?;
+
+            next = x
+                .checked_add_signed(dx)
+                .zip(y.checked_add_signed(dy))
+                .filter(|_| dx != 0 || dy != 0);
+
+            Some(cell)
+        })
+    }
+
+    fn offset_positions(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let nx = x.checked_add_signed(*dx)?;
+            let ny = y.checked_add_signed(*dy)?;
+
+            self.in_bounds(nx, ny).then_some((nx, ny))
+        })
+    }
+
+    /// In-bounds positions directly above, below, left and right of `(x, y)`.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions(x, y, &OFFSETS4)
+    }
+
+    /// Like [`Grid::neighbors4`], but also including the diagonals.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions(x, y, &OFFSETS8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("grid index out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("grid index out of bounds")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    #[test]
+    fn test_parse_rejects_ragged_rows() {
+        assert!(Grid::parse("ab\nc", Some).is_err());
+        assert!(Grid::parse("ab\nc?", |c| c.is_alphabetic().then_some(c)).is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse("abc\ndef\nghi", Some).unwrap();
+
+        let corner: Vec<_> = grid.neighbors4(0, 0).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = Grid::parse("abc\ndef\nghi", Some).unwrap();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        assert_eq!(grid.ray(0, 0, 1, 1).collect::<String>(), "aei");
+        assert_eq!(grid.ray(0, 2, 1, -1).collect::<String>(), "gec");
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.to_string(), "abc\ndef\nghi\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of bounds")]
+    fn test_column_out_of_bounds() {
+        let grid = Grid::parse("abc\ndef", Some).unwrap();
+        grid.column(3).for_each(drop);
+    }
+}
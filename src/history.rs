@@ -0,0 +1,396 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{format_duration, Entry};
+use crate::error::{Error, Result};
+
+/// How many earlier commits the trend of a measurement goes back.
+const TREND_LENGTH: usize = 8;
+
+/// A commit that benchmarks were run on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commit {
+    pub id: String,
+    /// Whether the working tree had changes that were not committed yet.
+    pub dirty: bool,
+}
+
+impl Display for Commit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", short_id(&self.id))?;
+        if self.dirty {
+            write!(f, " with uncommitted changes")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    &id[..id.len().min(7)]
+}
+
+fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| Error::Git(format!("could not run git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(Error::Git(format!(
+            "git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The commit that is checked out.
+pub fn head() -> Result<Commit> {
+    Ok(Commit {
+        id: resolve("HEAD")?,
+        dirty: !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty(),
+    })
+}
+
+/// The full id of the commit that `reference`, such as a branch, tag or short id, points at.
+pub fn resolve(reference: &str) -> Result<String> {
+    git(&[
+        "rev-parse",
+        "--verify",
+        &format!("{}^{{commit}}", reference),
+    ])
+}
+
+/// One benchmark measurement as kept in the history file, which has one JSON object per line.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Record {
+    pub commit: String,
+    pub dirty: bool,
+    /// When the measurement was taken, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: u32,
+    pub input: String,
+    pub phase: String,
+    pub iterations: usize,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Record {
+    pub fn new(commit: &Commit, entry: &Entry) -> Self {
+        let stats = &entry.measurement.stats;
+        let nanos = |duration: Duration| duration.as_nanos().try_into().unwrap_or(u64::MAX);
+
+        Record {
+            commit: commit.id.clone(),
+            dirty: commit.dirty,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            day: entry.day,
+            input: entry.input.to_string_lossy().into_owned(),
+            phase: entry.measurement.phase.to_string(),
+            iterations: stats.iterations,
+            median_ns: nanos(stats.median),
+            mean_ns: nanos(stats.mean),
+            stddev_ns: nanos(stats.stddev),
+        }
+    }
+
+    /// Measurements with the same key can be compared.
+    fn key(&self) -> (u32, &str, &str) {
+        (self.day, &self.input, &self.phase)
+    }
+}
+
+/// Loads the history at `path`, which is treated as empty if it doesn't exist yet.
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io(path, e)),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| {
+                let message = format!("invalid history file {}: {}", path.display(), e);
+                Error::parse_at(&contents, line, message)
+            })
+        })
+        .collect()
+}
+
+/// Adds `records` to the end of the history at `path`, creating it if needed.
+pub fn append(path: &Path, records: &[Record]) -> Result<()> {
+    let mut out = String::new();
+    for record in records {
+        out.push_str(&serde_json::to_string(record).expect("records are always serializable"));
+        out.push('\n');
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(out.as_bytes()))
+        .map_err(|e| Error::io(path, e))
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    /// The baseline commit has no measurement to compare with.
+    NoBaseline,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Regression => write!(f, "REGRESSION"),
+            Verdict::Improvement => write!(f, "improvement"),
+            Verdict::Unchanged => write!(f, "unchanged"),
+            Verdict::NoBaseline => write!(f, "no baseline"),
+        }
+    }
+}
+
+/// The two-sided 5% critical value of Student's t distribution with `df` degrees of freedom,
+/// using the Cornish-Fisher expansion around the normal distribution.
+fn t_critical(df: f64) -> f64 {
+    let z: f64 = 1.959964;
+
+    z + (z.powi(3) + z) / (4.0 * df)
+        + (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / (96.0 * df.powi(2))
+}
+
+/// Compares the mean times of two measurements with Welch's t-test.
+///
+/// A difference only counts if it is significant at the 5% level and larger than `threshold`,
+/// a fraction of the baseline, since with enough iterations even tiny differences are
+/// significant. Measurements of a single iteration are never significantly different.
+pub fn verdict(baseline: &Record, current: &Record, threshold: f64) -> Verdict {
+    let (n1, n2) = (baseline.iterations as f64, current.iterations as f64);
+    if n1 < 2.0 || n2 < 2.0 {
+        return Verdict::Unchanged;
+    }
+
+    // the stored deviation is of the whole population of samples
+    let variance = |record: &Record, n: f64| (record.stddev_ns as f64).powi(2) * n / (n - 1.0);
+    let (e1, e2) = (variance(baseline, n1) / n1, variance(current, n2) / n2);
+
+    let (m1, m2) = (baseline.mean_ns as f64, current.mean_ns as f64);
+    let significant = if e1 + e2 == 0.0 {
+        m1 != m2
+    } else {
+        let t = (m2 - m1) / (e1 + e2).sqrt();
+        let df = (e1 + e2).powi(2) / (e1.powi(2) / (n1 - 1.0) + e2.powi(2) / (n2 - 1.0));
+        t.abs() > t_critical(df)
+    };
+
+    if !significant || (m2 - m1).abs() <= threshold * m1 {
+        Verdict::Unchanged
+    } else if m2 > m1 {
+        Verdict::Regression
+    } else {
+        Verdict::Improvement
+    }
+}
+
+/// A new measurement compared with the one from the baseline commit.
+#[derive(Clone, Debug)]
+pub struct Comparison<'a> {
+    pub current: &'a Record,
+    pub baseline: Option<&'a Record>,
+    /// Mean times from up to 8 earlier commits, oldest first, then the current one.
+    pub trend: Vec<u64>,
+    pub verdict: Verdict,
+}
+
+/// Compares each of `current` with the latest measurement taken on `baseline` in `history`.
+pub fn compare<'a>(
+    history: &'a [Record],
+    baseline: &str,
+    current: &'a [Record],
+    threshold: f64,
+) -> Vec<Comparison<'a>> {
+    current
+        .iter()
+        .map(|record| {
+            let earlier: Vec<_> = history
+                .iter()
+                .filter(|other| other.key() == record.key())
+                .collect();
+
+            let baseline = earlier.iter().rev().find(|other| other.commit == baseline);
+
+            // the latest measurement for each commit, in the order they were last measured
+            let mut seen = HashSet::new();
+            let mut trend: Vec<_> = earlier
+                .iter()
+                .rev()
+                .filter(|other| seen.insert(&other.commit))
+                .take(TREND_LENGTH)
+                .map(|other| other.mean_ns)
+                .collect();
+            trend.reverse();
+            trend.push(record.mean_ns);
+
+            Comparison {
+                current: record,
+                baseline: baseline.copied(),
+                trend,
+                verdict: match baseline {
+                    Some(baseline) => verdict(baseline, record, threshold),
+                    None => Verdict::NoBaseline,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Draws `values` as a line of bars from the lowest to the highest.
+fn sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let (Some(low), Some(high)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+
+    values
+        .iter()
+        .map(|value| {
+            if high == low {
+                BARS[3]
+            } else {
+                BARS[((value - low) * 7 / (high - low)) as usize]
+            }
+        })
+        .collect()
+}
+
+/// An aligned table of the comparisons, with the mean times and how they changed.
+pub fn to_trend_table(comparisons: &[Comparison]) -> String {
+    let days: HashSet<_> = comparisons.iter().map(|c| c.current.day).collect();
+    let inputs: HashSet<_> = comparisons
+        .iter()
+        .map(|c| (c.current.day, &c.current.input))
+        .collect();
+    let show_inputs = inputs.len() > days.len();
+
+    let mut out = format!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>8}  {:<11}  {:<9}",
+        "Day", "Phase", "Baseline", "Current", "Change", "Verdict", "Trend"
+    );
+    if show_inputs {
+        out.push_str("  Input");
+    }
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+
+    for comparison in comparisons {
+        let current = comparison.current;
+        let mean = |record: &Record| format_duration(Duration::from_nanos(record.mean_ns));
+
+        let (baseline, change) = match comparison.baseline {
+            Some(baseline) if baseline.mean_ns > 0 => {
+                let change = (current.mean_ns as f64 / baseline.mean_ns as f64 - 1.0) * 100.0;
+                (mean(baseline), format!("{:+.1}%", change))
+            }
+            Some(baseline) => (mean(baseline), String::new()),
+            None => (String::new(), String::new()),
+        };
+
+        out.push_str(&format!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>8}  {:<11}  {:<9}",
+            current.day,
+            current.phase,
+            baseline,
+            mean(current),
+            change,
+            comparison.verdict.to_string(),
+            sparkline(&comparison.trend)
+        ));
+        if show_inputs {
+            out.push_str(&format!("  {}", current.input));
+        }
+
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, sparkline, verdict, Record, Verdict};
+
+    fn record(commit: &str, mean_ns: u64, stddev_ns: u64) -> Record {
+        Record {
+            commit: String::from(commit),
+            dirty: false,
+            timestamp: 0,
+            day: 6,
+            input: String::from("inputs/day06.txt"),
+            phase: String::from("part2"),
+            iterations: 20,
+            median_ns: mean_ns,
+            mean_ns,
+            stddev_ns,
+        }
+    }
+
+    #[test]
+    fn test_verdict() {
+        let baseline = record("a", 1_000_000, 20_000);
+
+        let slower = record("b", 1_200_000, 20_000);
+        assert_eq!(verdict(&baseline, &slower, 0.05), Verdict::Regression);
+        assert_eq!(verdict(&slower, &baseline, 0.05), Verdict::Improvement);
+
+        // significant, but too small to matter
+        let slightly_slower = record("b", 1_030_000, 20_000);
+        assert_eq!(
+            verdict(&baseline, &slightly_slower, 0.05),
+            Verdict::Unchanged
+        );
+
+        // large, but within the noise
+        let noisy = record("b", 1_200_000, 1_000_000);
+        assert_eq!(verdict(&baseline, &noisy, 0.05), Verdict::Unchanged);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = [
+            record("a", 100, 0),
+            record("b", 300, 0),
+            record("a", 200, 0),
+        ];
+        let current = [record("c", 400, 0)];
+
+        let comparisons = compare(&history, "a", &current, 0.05);
+        assert_eq!(comparisons[0].baseline, Some(&history[2]));
+        assert_eq!(comparisons[0].trend, [300, 200, 400]);
+        assert_eq!(comparisons[0].verdict, Verdict::Regression);
+
+        assert_eq!(sparkline(&comparisons[0].trend), "▄▁█");
+    }
+}
@@ -0,0 +1,19 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+
+/// Reads a puzzle input, treating a path of `-` as stdin.
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|e| Error::io("<stdin>", e))?;
+
+        return Ok(contents);
+    }
+
+    fs::read_to_string(path).map_err(|e| Error::io(path, e))
+}
@@ -0,0 +1,23 @@
+pub mod answers;
+pub mod bench;
+pub mod budget;
+pub mod cli;
+pub mod client;
+pub mod days;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod mem;
+pub mod parallel;
+pub mod render;
+pub mod report;
+pub mod rng;
+pub mod serve;
+pub mod solution;
+pub mod validate;
+
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use solution::{DynSolution, Part, Solution};
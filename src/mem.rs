@@ -0,0 +1,314 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+use crate::bench::{self, Phase};
+use crate::error::Result;
+use crate::solution::{DynSolution, Part};
+
+/// Wraps the system allocator, counting allocations while a [`measure`] is running, and the heap
+/// in use once [`track_heap`] has been called.
+///
+/// Otherwise the only overhead is checking two flags. Binaries opt in by declaring it their
+/// `#[global_allocator]`; without it, [`measure`] counts nothing and memory budgets never run out.
+pub struct CountingAllocator;
+
+// the library's own tests measure allocations too
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// live bytes relative to the start of the measurement, so memory allocated earlier and freed
+// during it makes this negative
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// the heap in use by the whole process since tracking began, kept for memory budgets
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static HEAP: AtomicIsize = AtomicIsize::new(0);
+
+fn on_alloc(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        record_alloc(size);
+    }
+    if TRACKING.load(Ordering::Relaxed) {
+        HEAP.fetch_add(size as isize, Ordering::Relaxed);
+    }
+}
+
+fn on_dealloc(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        record_dealloc(size);
+    }
+    if TRACKING.load(Ordering::Relaxed) {
+        HEAP.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+
+    let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        on_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        // counted as a new allocation followed by freeing the old one
+        if !new_ptr.is_null() {
+            on_alloc(new_size);
+            on_dealloc(layout.size());
+        }
+
+        new_ptr
+    }
+}
+
+/// Heap usage while running some code, including any threads it spawns work onto.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+    /// The most memory that was live at once, beyond what was allocated beforehand.
+    pub peak: usize,
+    /// Total bytes allocated, even if they were freed again.
+    pub allocated: usize,
+    pub allocations: usize,
+}
+
+/// Runs `f`, counting the allocations it makes.
+///
+/// Measurements can't be nested or run concurrently, as the counters are global.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    LIVE.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    ALLOCATED.store(0, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ENABLED.store(true, Ordering::SeqCst);
+
+    let result = f();
+
+    ENABLED.store(false, Ordering::SeqCst);
+
+    let usage = Usage {
+        peak: PEAK.load(Ordering::Relaxed) as usize,
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    };
+
+    (result, usage)
+}
+
+/// Starts keeping count of the heap in use, for [`heap_in_use`]. Tracking can't be stopped.
+pub fn track_heap() {
+    TRACKING.store(true, Ordering::SeqCst);
+}
+
+/// Bytes allocated and not yet freed since [`track_heap`] was first called.
+///
+/// Memory allocated before then and freed since is subtracted, so only differences between two
+/// readings are meaningful.
+pub fn heap_in_use() -> isize {
+    HEAP.load(Ordering::Relaxed)
+}
+
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub day: u32,
+    pub input: PathBuf,
+    pub phase: Phase,
+    pub usage: Usage,
+}
+
+/// Measures parsing and each of `parts` separately.
+///
+/// The parsed input is kept alive while solving, so it isn't counted against the parts.
+pub fn run(solution: &dyn DynSolution, input: &str, parts: &[Part]) -> Result<Vec<(Phase, Usage)>> {
+    let (parsed, usage) = measure(|| solution.parse(input));
+    let parsed = parsed?;
+
+    let mut usages = vec![(Phase::Parse, usage)];
+    for part in parts {
+        let (answer, usage) = measure(|| solution.solve(parsed.as_ref(), *part));
+        answer?;
+
+        usages.push((Phase::Solve(*part), usage));
+    }
+
+    Ok(usages)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[derive(Serialize)]
+struct Record {
+    day: u32,
+    input: String,
+    phase: String,
+    peak_bytes: usize,
+    allocated_bytes: usize,
+    allocations: usize,
+}
+
+impl Record {
+    fn new(entry: &Entry) -> Self {
+        Record {
+            day: entry.day,
+            input: entry.input.to_string_lossy().into_owned(),
+            phase: entry.phase.to_string(),
+            peak_bytes: entry.usage.peak,
+            allocated_bytes: entry.usage.allocated,
+            allocations: entry.usage.allocations,
+        }
+    }
+}
+
+/// An aligned table for reading in a terminal.
+///
+/// Inputs are only listed when some day was measured on more than one of them.
+pub fn to_table(entries: &[Entry]) -> String {
+    let days: HashSet<_> = entries.iter().map(|entry| entry.day).collect();
+    let inputs: HashSet<_> = entries
+        .iter()
+        .map(|entry| (entry.day, &entry.input))
+        .collect();
+    let show_inputs = inputs.len() > days.len();
+
+    let mut out = format!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>11}",
+        "Day", "Phase", "Peak", "Allocated", "Allocations"
+    );
+    if show_inputs {
+        out.push_str("  Input");
+    }
+    out.push('\n');
+
+    for entry in entries {
+        out.push_str(&format!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>11}",
+            entry.day,
+            entry.phase.to_string(),
+            format_bytes(entry.usage.peak),
+            format_bytes(entry.usage.allocated),
+            entry.usage.allocations
+        ));
+        if show_inputs {
+            out.push_str(&format!("  {}", entry.input.display()));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// One line per measurement, with sizes in bytes.
+pub fn to_csv(entries: &[Entry]) -> String {
+    bench::records_to_csv(entries.iter().map(Record::new))
+}
+
+/// A JSON array with one object per measurement, using the same fields as [`to_csv`].
+pub fn to_json(entries: &[Entry]) -> String {
+    let records: Vec<_> = entries.iter().map(Record::new).collect();
+
+    serde_json::to_string_pretty(&records).expect("records are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{format_bytes, measure, to_csv, Entry, Usage};
+    use crate::bench::Phase;
+
+    #[test]
+    fn test_measure() {
+        let (_, usage) = measure(|| {
+            let a = black_box(vec![0u8; 1000]);
+            drop(a);
+
+            black_box(vec![0u8; 600])
+        });
+
+        // tests run in parallel threads, so other allocations and frees may be counted too, which
+        // leaves the peak too unpredictable to check
+        assert!(usage.allocations >= 2);
+        assert!(usage.allocated >= 1600);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_csv_quotes_inputs() {
+        let entry = Entry {
+            day: 8,
+            input: "my inputs,\nday08.txt".into(),
+            phase: Phase::Parse,
+            usage: Usage {
+                peak: 10,
+                allocated: 20,
+                allocations: 2,
+            },
+        };
+
+        assert_eq!(
+            to_csv(&[entry]),
+            "day,input,phase,peak_bytes,allocated_bytes,allocations\n\
+             8,\"my inputs,\nday08.txt\",parse,10,20,2\n"
+        );
+    }
+}
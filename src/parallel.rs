@@ -0,0 +1,114 @@
+//! Work that runs on the rayon thread pool with the `parallel` feature, and one item at a time
+//! without it.
+//!
+//! Results always come back in the order of the items, so anything folded from them, such as a
+//! sum of floats, is the same in both builds.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::error::Result;
+
+/// Applies `f` to every item, returning the results in order.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().map(f).collect();
+}
+
+/// Like [`map`], but also passes `f` the index of each item.
+pub fn map_indexed<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(usize, &T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items
+        .par_iter()
+        .enumerate()
+        .map(|(index, item)| f(index, item))
+        .collect();
+
+    #[cfg(not(feature = "parallel"))]
+    return items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| f(index, item))
+        .collect();
+}
+
+/// Like [`map`], but stops early once `f` fails for any item.
+///
+/// When several items fail, which of their errors is returned depends on scheduling.
+pub fn try_map<T, R, F>(items: &[T], f: F) -> Result<Vec<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().map(f).collect();
+}
+
+/// Sets how many threads the pool has, which must happen before any work is run on it.
+///
+/// Without the `parallel` feature there is only ever one thread, and asking for more logs a
+/// warning.
+pub fn set_threads(threads: usize) {
+    #[cfg(feature = "parallel")]
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .expect("the thread pool is only set up once");
+
+    #[cfg(not(feature = "parallel"))]
+    if threads > 1 {
+        log::warn!(
+            "built without the parallel feature, so running on one thread instead of {}",
+            threads
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map, map_indexed, try_map};
+    use crate::Error;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u32> = (0..1000).collect();
+
+        assert_eq!(
+            map(&items, |x| x * 2),
+            (0..2000).step_by(2).collect::<Vec<_>>()
+        );
+        assert!(map_indexed(&items, |index, x| index as u32 == *x)
+            .into_iter()
+            .all(|same| same));
+    }
+
+    #[test]
+    fn test_try_map() {
+        let items: Vec<u32> = (0..1000).collect();
+
+        assert_eq!(try_map(&items, |x| Ok(x + 1)).unwrap()[999], 1000);
+
+        let result = try_map(&items, |x| match x {
+            500 => Err(Error::Unsolvable(String::from("500"))),
+            _ => Ok(*x),
+        });
+        assert!(matches!(result, Err(Error::Unsolvable(_))));
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::geometry::Direction;
+use crate::grid::Grid;
+
+/// Pixels per cell in PPM images.
+const PPM_CELL: usize = 8;
+/// Units per cell in SVG images.
+const SVG_CELL: usize = 16;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BACKGROUND: Color = Color::rgb(0x1e, 0x1e, 0x1e);
+    pub const DIM: Color = Color::rgb(0x50, 0x50, 0x50);
+    pub const GRAY: Color = Color::rgb(0xa0, 0xa0, 0xa0);
+    pub const WHITE: Color = Color::rgb(0xff, 0xff, 0xff);
+    pub const BLACK: Color = Color::rgb(0x00, 0x00, 0x00);
+    pub const RED: Color = Color::rgb(0xe0, 0x40, 0x40);
+    pub const GREEN: Color = Color::rgb(0x40, 0xd0, 0x60);
+    pub const BLUE: Color = Color::rgb(0x40, 0x70, 0xe0);
+    pub const YELLOW: Color = Color::rgb(0xe0, 0xc0, 0x40);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+
+    /// A colour for the `index`th of some unordered set of things.
+    ///
+    /// Hues are a golden angle apart, so consecutive indices never look alike.
+    pub fn category(index: usize) -> Self {
+        let hue = (index as f64 * 137.507_764) % 360.0;
+        Color::hsv(hue, 0.55, 0.85)
+    }
+
+    /// Blends from `low` at `t = 0` to `high` at `t = 1`.
+    pub fn gradient(low: Color, high: Color, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Color::rgb(mix(low.r, high.r), mix(low.g, high.g), mix(low.b, high.b))
+    }
+
+    /// The same hue with each channel scaled by `factor`.
+    pub fn darken(self, factor: f64) -> Self {
+        Color::gradient(Color::BLACK, self, factor)
+    }
+
+    fn hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 / 60 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let channel = |c: f64| ((c + value - chroma) * 255.0).round() as u8;
+        Color::rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+    pub background: Option<Color>,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Cell {
+            glyph,
+            color,
+            background: None,
+        }
+    }
+
+    pub const fn on(self, background: Color) -> Self {
+        Cell {
+            background: Some(background),
+            ..self
+        }
+    }
+}
+
+/// A grid of coloured characters, along with fences drawn on the edges between cells.
+///
+/// Fences only show up in images; in the terminal cells are told apart by colour alone.
+#[derive(Clone, Debug)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+    fences: HashSet<((usize, usize), Direction)>,
+}
+
+impl Canvas {
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl FnMut(&T) -> Cell) -> Self {
+        Canvas {
+            cells: grid.map(f),
+            fences: HashSet::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, x: I, y: I) -> Option<&Cell> {
+        self.cells.get(x, y)
+    }
+
+    /// Replaces the cell at `(x, y)`, ignoring positions off the canvas.
+    pub fn set<I: TryInto<usize>>(&mut self, x: I, y: I, cell: Cell) {
+        if let Some(existing) = self.cells.get_mut(x, y) {
+            *existing = cell;
+        }
+    }
+
+    /// Draws a fence along the `side` edge of the cell at `(x, y)`.
+    pub fn fence<I: TryInto<usize>>(&mut self, x: I, y: I, side: Direction) {
+        if let (Ok(x), Ok(y)) = (x.try_into(), y.try_into()) {
+            if self.cells.in_bounds(x, y) {
+                self.fences.insert(((x, y), side));
+            }
+        }
+    }
+
+    pub fn fence_count(&self) -> usize {
+        self.fences.len()
+    }
+
+    fn has_fence(&self, x: usize, y: usize, side: Direction) -> bool {
+        self.fences.contains(&((x, y), side))
+    }
+
+    /// One line per row, coloured with 24-bit ANSI escape codes.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+
+        for row in self.cells.rows() {
+            for cell in row {
+                let Color { r, g, b } = cell.color;
+                out.push_str(&format!("\x1b[38;2;{};{};{}", r, g, b));
+
+                match cell.background {
+                    Some(Color { r, g, b }) => out.push_str(&format!(";48;2;{};{};{}m", r, g, b)),
+                    None => out.push_str(";49m"),
+                }
+
+                out.push(cell.glyph);
+            }
+
+            out.push_str("\x1b[0m\n");
+        }
+
+        out
+    }
+
+    /// A binary PPM image, drawing each glyph as a square in its colour.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let width = self.width() * PPM_CELL;
+        let height = self.height() * PPM_CELL;
+
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        out.reserve(width * height * 3);
+
+        let inner = PPM_CELL / 4..PPM_CELL - PPM_CELL / 4;
+        let last = PPM_CELL - 1;
+
+        for py in 0..height {
+            for px in 0..width {
+                let (x, y) = (px / PPM_CELL, py / PPM_CELL);
+                let (cx, cy) = (px % PPM_CELL, py % PPM_CELL);
+                let cell = &self.cells[(x, y)];
+
+                let on_fence = (cy == 0 && self.has_fence(x, y, Direction::Up))
+                    || (cy == last && self.has_fence(x, y, Direction::Down))
+                    || (cx == 0 && self.has_fence(x, y, Direction::Left))
+                    || (cx == last && self.has_fence(x, y, Direction::Right));
+
+                let color = if on_fence {
+                    Color::BLACK
+                } else if !cell.glyph.is_whitespace() && inner.contains(&cx) && inner.contains(&cy)
+                {
+                    cell.color
+                } else {
+                    cell.background.unwrap_or(Color::BACKGROUND)
+                };
+
+                out.extend([color.r, color.g, color.b]);
+            }
+        }
+
+        out
+    }
+
+    /// An SVG image with the glyphs drawn as text.
+    pub fn to_svg(&self) -> String {
+        let width = self.width() * SVG_CELL;
+        let height = self.height() * SVG_CELL;
+
+        let mut out = format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" ",
+                "viewBox=\"0 0 {0} {1}\" font-family=\"monospace\" font-size=\"{2}\" ",
+                "text-anchor=\"middle\" dominant-baseline=\"central\">\n",
+                "<rect width=\"{0}\" height=\"{1}\" fill=\"{3}\"/>\n"
+            ),
+            width,
+            height,
+            SVG_CELL * 3 / 4,
+            hex(Color::BACKGROUND)
+        );
+
+        for ((x, y), cell) in self.cells.iter() {
+            let (left, top) = (x * SVG_CELL, y * SVG_CELL);
+
+            if let Some(background) = cell.background {
+                out.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{2}\" height=\"{2}\" fill=\"{3}\"/>\n",
+                    left,
+                    top,
+                    SVG_CELL,
+                    hex(background)
+                ));
+            }
+
+            if !cell.glyph.is_whitespace() {
+                out.push_str(&format!(
+                    "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>\n",
+                    left + SVG_CELL / 2,
+                    top + SVG_CELL / 2,
+                    hex(cell.color),
+                    escape(cell.glyph)
+                ));
+            }
+        }
+
+        // sorted so that the same canvas always gives the same file
+        let mut fences: Vec<_> = self.fences.iter().collect();
+        fences
+            .sort_by_key(|((x, y), side)| (*y, *x, Direction::ALL.iter().position(|d| d == side)));
+
+        for ((x, y), side) in fences {
+            let (left, top) = (x * SVG_CELL, y * SVG_CELL);
+            let (right, bottom) = (left + SVG_CELL, top + SVG_CELL);
+
+            let (x1, y1, x2, y2) = match side {
+                Direction::Up => (left, top, right, top),
+                Direction::Right => (right, top, right, bottom),
+                Direction::Down => (left, bottom, right, bottom),
+                Direction::Left => (left, top, left, bottom),
+            };
+
+            out.push_str(&format!(
+                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"2\"/>\n",
+                x1,
+                y1,
+                x2,
+                y2,
+                hex(Color::BLACK)
+            ));
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+fn escape(glyph: char) -> String {
+    match glyph {
+        '&' => String::from("&amp;"),
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        c => c.to_string(),
+    }
+}
+
+/// Where a rendering goes, picked from the path given to `--render`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Target {
+    /// `-`, for ANSI text on stdout.
+    Terminal,
+    Ppm(PathBuf),
+    Svg(PathBuf),
+}
+
+impl Target {
+    /// Parses a `--render` value, failing for file extensions that aren't supported.
+    pub fn parse(value: &str) -> std::result::Result<Self, String> {
+        if value == "-" {
+            return Ok(Target::Terminal);
+        }
+
+        let path = PathBuf::from(value);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("svg") => Ok(Target::Svg(path)),
+            Some(extension) if extension.eq_ignore_ascii_case("ppm") => Ok(Target::Ppm(path)),
+            _ => Err(String::from(
+                "expected a path ending in .svg or .ppm, or - for the terminal",
+            )),
+        }
+    }
+
+    pub fn write(&self, canvas: &Canvas) -> Result<()> {
+        match self {
+            Target::Terminal => io::stdout()
+                .write_all(canvas.to_ansi().as_bytes())
+                .map_err(|e| Error::io("<stdout>", e)),
+            Target::Ppm(path) => write_file(path, &canvas.to_ppm()),
+            Target::Svg(path) => write_file(path, canvas.to_svg().as_bytes()),
+        }
+    }
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    fs::write(path, contents).map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Canvas, Cell, Color, Target, PPM_CELL};
+    use crate::geometry::Direction;
+    use crate::Grid;
+
+    fn canvas() -> Canvas {
+        let grid = Grid::parse("a.\n.<", Some).unwrap();
+        let mut canvas = Canvas::from_grid(&grid, |c| Cell::new(*c, Color::WHITE));
+
+        canvas.set(1, 0, Cell::new('#', Color::RED).on(Color::BLUE));
+        canvas.fence(0, 0, Direction::Right);
+        canvas
+    }
+
+    #[test]
+    fn test_ansi() {
+        let ansi = canvas().to_ansi();
+        let lines: Vec<_> = ansi.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\x1b[38;2;224;64;64;48;2;64;112;224m#"));
+        assert!(lines[1].ends_with("<\x1b[0m"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = canvas().to_ppm();
+        let header = format!("P6\n{0} {0}\n255\n", 2 * PPM_CELL);
+
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(ppm.len(), header.len() + 3 * (2 * PPM_CELL).pow(2));
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = canvas().to_svg();
+
+        assert!(svg.contains(">&lt;</text>"));
+        assert!(svg.contains("fill=\"#4070e0\""));
+        assert_eq!(svg.matches("<line").count(), 1);
+    }
+
+    #[test]
+    fn test_target() {
+        assert_eq!(Target::parse("-"), Ok(Target::Terminal));
+        assert_eq!(
+            Target::parse("out.SVG"),
+            Ok(Target::Svg(PathBuf::from("out.SVG")))
+        );
+        assert_eq!(
+            Target::parse("a/b.ppm"),
+            Ok(Target::Ppm(PathBuf::from("a/b.ppm")))
+        );
+        assert!(Target::parse("out.png").is_err());
+    }
+}
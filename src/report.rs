@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::budget::Budget;
+use crate::error::{Error, Result};
+use crate::input::read_input;
+use crate::solution::{DynSolution, Part};
+
+/// The result of solving one part, along with how long it took.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub day: u32,
+    pub part: Part,
+    /// The file the input was read from, or `-` for stdin.
+    pub input: PathBuf,
+    pub answer: Result<String>,
+    /// Time spent parsing the input, shared by every part. `None` if the input wasn't parsed.
+    pub parse_time: Option<Duration>,
+    /// Time spent in the part itself. `None` if it never ran.
+    pub solve_time: Option<Duration>,
+}
+
+/// Reads and parses `input_path` once and solves each of `parts`.
+///
+/// Parsing and each part are given the whole `budget` separately. Errors reading or parsing the
+/// input are reported against every part.
+pub fn solve(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    parts: &[Part],
+    budget: &Budget,
+) -> Vec<Report> {
+    let contents = read_input(input_path);
+    solve_contents(
+        day,
+        solution,
+        input_path,
+        contents.as_deref().map_err(Error::clone),
+        parts,
+        budget,
+    )
+}
+
+/// Like [`solve`], but for an input that was read already, which is reported as coming from
+/// `input_path`.
+pub fn solve_str(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    contents: &str,
+    parts: &[Part],
+    budget: &Budget,
+) -> Vec<Report> {
+    solve_contents(day, solution, input_path, Ok(contents), parts, budget)
+}
+
+fn solve_contents(
+    day: u32,
+    solution: &dyn DynSolution,
+    input_path: &Path,
+    contents: Result<&str>,
+    parts: &[Part],
+    budget: &Budget,
+) -> Vec<Report> {
+    let start = Instant::now();
+    let parsed = contents.and_then(|contents| budget.run(|| solution.parse(contents)));
+    let parse_time = parsed.is_ok().then(|| start.elapsed());
+
+    parts
+        .iter()
+        .map(|part| {
+            let (answer, solve_time) = match &parsed {
+                Ok(parsed) => {
+                    let start = Instant::now();
+                    let answer = budget.run(|| solution.solve(parsed.as_ref(), *part));
+                    (answer, Some(start.elapsed()))
+                }
+                Err(e) => (Err(e.clone()), None),
+            };
+
+            Report {
+                day,
+                part: *part,
+                input: input_path.to_path_buf(),
+                answer,
+                parse_time,
+                solve_time,
+            }
+        })
+        .collect()
+}
+
+#[derive(Serialize)]
+struct ErrorRecord {
+    kind: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+impl From<&Error> for ErrorRecord {
+    fn from(e: &Error) -> Self {
+        let location = match e {
+            Error::Parse { location, .. } => location.as_ref(),
+            _ => None,
+        };
+
+        ErrorRecord {
+            kind: e.kind(),
+            message: e.to_string(),
+            line: location.map(|l| l.line),
+            column: location.map(|l| l.column),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Record {
+    day: u32,
+    part: u8,
+    input: String,
+    /// Always a string, so that large integers and floats survive JSON parsers unchanged.
+    answer: Option<String>,
+    parse_ns: Option<u128>,
+    solve_ns: Option<u128>,
+    error: Option<ErrorRecord>,
+}
+
+impl Report {
+    /// The report as a JSON object, with `null` for any missing answer, timing or error.
+    pub fn to_json(&self) -> Value {
+        let record = Record {
+            day: self.day,
+            part: match self.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            input: self.input.to_string_lossy().into_owned(),
+            answer: self.answer.as_ref().ok().cloned(),
+            parse_ns: self.parse_time.map(|t| t.as_nanos()),
+            solve_ns: self.solve_time.map(|t| t.as_nanos()),
+            error: self.answer.as_ref().err().map(ErrorRecord::from),
+        };
+
+        serde_json::to_value(record).expect("records are always serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Report;
+    use crate::error::Error;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_to_json() {
+        let input = "1 2\n3 x\n";
+        let report = Report {
+            day: 1,
+            part: Part::Two,
+            input: "-".into(),
+            answer: Err(Error::parse_at(input, &input[6..], "invalid digit")),
+            parse_time: None,
+            solve_time: None,
+        };
+
+        assert_eq!(
+            report.to_json().to_string(),
+            concat!(
+                r#"{"day":1,"part":2,"input":"-","answer":null,"parse_ns":null,"solve_ns":null,"#,
+                r#""error":{"kind":"parse","message":"could not parse input: invalid digit "#,
+                r#"at line 2, column 3","line":2,"column":3}}"#
+            )
+        );
+    }
+}
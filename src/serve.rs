@@ -0,0 +1,248 @@
+use std::any::Any;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::thread;
+
+use log::debug;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response};
+
+use crate::budget::Budget;
+use crate::days;
+use crate::error::{Error, Result};
+use crate::report;
+use crate::solution::{DynSolution, Part};
+
+/// What a response reports the input as coming from.
+const BODY: &str = "body";
+
+/// The largest input accepted unless [`Server::with_max_body`] says otherwise.
+const DEFAULT_MAX_BODY: u64 = 16 << 20;
+
+/// Looks up the solution for a day, like [`days::get`].
+pub type Days = fn(u32) -> Option<&'static dyn DynSolution>;
+
+/// Serves the solvers as a JSON API, where `POST /day/{n}/part/{p}` solves the input in the
+/// request body.
+pub struct Server {
+    server: tiny_http::Server,
+    budget: Budget,
+    days: Days,
+    max_body: u64,
+}
+
+/// A request that can't be answered, with the status to send.
+struct Rejection {
+    status: u16,
+    message: String,
+}
+
+impl Rejection {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Rejection {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl Server {
+    /// Listens on `addr`, such as `127.0.0.1:8080`. Port 0 picks a free port.
+    pub fn bind(addr: &str) -> Result<Self> {
+        let server = tiny_http::Server::http(addr)
+            .map_err(|e| Error::Http(format!("could not listen on {}: {}", addr, e)))?;
+
+        Ok(Server {
+            server,
+            budget: Budget::default(),
+            days: days::get,
+            max_body: DEFAULT_MAX_BODY,
+        })
+    }
+
+    /// Limits parsing and solving each request to `budget`.
+    ///
+    /// Heap usage is counted for the whole process, so requests answered at the same time count
+    /// against each other's memory budget.
+    pub fn with_budget(self, budget: Budget) -> Self {
+        Server { budget, ..self }
+    }
+
+    /// Rejects inputs larger than `max_body` bytes.
+    pub fn with_max_body(self, max_body: u64) -> Self {
+        Server { max_body, ..self }
+    }
+
+    /// Serves the solutions `days` looks up instead of the ones in [`days`].
+    pub fn with_days(self, days: Days) -> Self {
+        Server { days, ..self }
+    }
+
+    /// The address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.server
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on an IP address")
+    }
+
+    /// Answers requests, each on a thread of its own, until [`Server::unblock`] is called.
+    ///
+    /// Reading a request can block for as long as the client takes to send it, so requests are
+    /// kept off the thread pool that the solvers use.
+    pub fn run(&self) {
+        for request in self.server.incoming_requests() {
+            let budget = self.budget;
+            let days = self.days;
+            let max_body = self.max_body;
+            thread::spawn(move || handle(request, &budget, days, max_body));
+        }
+    }
+
+    /// Makes [`Server::run`] return.
+    pub fn unblock(&self) {
+        self.server.unblock();
+    }
+}
+
+/// The day and part of a path like `/day/7/part/2`.
+fn route(url: &str) -> Option<(u32, Part)> {
+    let path = url.split('?').next().unwrap_or(url);
+    let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+
+    let ["day", day, "part", part] = segments[..] else {
+        return None;
+    };
+
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+
+    Some((day.parse().ok()?, part))
+}
+
+/// Reads the body of `request`, which must be at most `max_body` bytes.
+fn read_body(request: &mut Request, max_body: u64) -> std::result::Result<String, Rejection> {
+    let too_large = || Rejection::new(413, format!("the input is over {} bytes", max_body));
+
+    if request
+        .body_length()
+        .is_some_and(|length| length as u64 > max_body)
+    {
+        return Err(too_large());
+    }
+
+    // the length isn't always sent, so the limit is also kept while reading
+    let mut input = String::new();
+    request
+        .as_reader()
+        .take(max_body + 1)
+        .read_to_string(&mut input)
+        .map_err(|e| Rejection::new(400, format!("could not read the input: {}", e)))?;
+
+    if input.len() as u64 > max_body {
+        return Err(too_large());
+    }
+
+    Ok(input)
+}
+
+fn solve(
+    request: &mut Request,
+    budget: &Budget,
+    days: Days,
+    max_body: u64,
+) -> std::result::Result<(u16, Value), Rejection> {
+    let url = request.url().to_string();
+    let (day, part) =
+        route(&url).ok_or_else(|| Rejection::new(404, format!("no such endpoint {}", url)))?;
+
+    let solution: &dyn DynSolution =
+        days(day).ok_or_else(|| Rejection::new(404, format!("no solution for day {}", day)))?;
+
+    if *request.method() != Method::Post {
+        return Err(Rejection::new(
+            405,
+            format!("{} needs the input POSTed to it", url),
+        ));
+    }
+
+    let input = read_body(request, max_body)?;
+
+    let report = report::solve_str(day, solution, Path::new(BODY), &input, &[part], budget)
+        .pop()
+        .expect("one report per part");
+
+    let mut value = report.to_json();
+    let object = value.as_object_mut().expect("reports are objects");
+    object.insert(
+        "diagnostic".into(),
+        report.answer.as_ref().err().map(Error::diagnostic).into(),
+    );
+
+    // the request was fine, but the input was not
+    let status = if report.answer.is_ok() { 200 } else { 422 };
+
+    Ok((status, value))
+}
+
+/// The message a panic was raised with, when it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "no message"
+    }
+}
+
+fn handle(mut request: Request, budget: &Budget, days: Days, max_body: u64) {
+    // a solver that panics fails its own request rather than taking the server down with it
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        solve(&mut request, budget, days, max_body)
+    }));
+
+    let (status, body) = match solved {
+        Ok(Ok(response)) => response,
+        Ok(Err(rejection)) => (
+            rejection.status,
+            json!({"error": {"kind": "request", "message": rejection.message}}),
+        ),
+        Err(payload) => (
+            500,
+            json!({"error": {"kind": "panic", "message": panic_message(payload.as_ref())}}),
+        ),
+    };
+
+    debug!("{} {} -> {}", request.method(), request.url(), status);
+
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json").expect("the header is valid");
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+
+    if let Err(e) = request.respond(response) {
+        debug!("could not respond: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::route;
+    use crate::Part;
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("/day/7/part/2"), Some((7, Part::Two)));
+        assert_eq!(route("/day/13/part/1/?pretty"), Some((13, Part::One)));
+        assert_eq!(route("/day/7/part/3"), None);
+        assert_eq!(route("/day/x/part/1"), None);
+        assert_eq!(route("/days"), None);
+    }
+}
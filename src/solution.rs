@@ -0,0 +1,85 @@
+use std::any::Any;
+use std::fmt::{self, Display};
+
+use crate::error::Result;
+use crate::render::Canvas;
+
+/// A solver for a single day's puzzle.
+///
+/// The raw input is parsed once and then shared between both parts.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Output1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Output2>;
+
+    /// Draws the input along with what the solution found in it, for days that can.
+    fn render(&self, _input: &Self::Input) -> Option<Canvas> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], used to pick a day at runtime.
+///
+/// Parsed inputs are passed around as `dyn Any` and answers are rendered to strings.
+pub trait DynSolution: Send + Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>>;
+    fn solve(&self, input: &(dyn Any + Send + Sync), part: Part) -> Result<String>;
+    fn render(&self, input: &(dyn Any + Send + Sync)) -> Option<Canvas>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn solve(&self, input: &(dyn Any + Send + Sync), part: Part) -> Result<String> {
+        match part {
+            Part::One => self
+                .part1(downcast::<S>(input))
+                .map(|answer| answer.to_string()),
+            Part::Two => self
+                .part2(downcast::<S>(input))
+                .map(|answer| answer.to_string()),
+        }
+    }
+
+    fn render(&self, input: &(dyn Any + Send + Sync)) -> Option<Canvas> {
+        Solution::render(self, downcast::<S>(input))
+    }
+}
+
+fn downcast<S: Solution>(input: &(dyn Any + Send + Sync)) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different solution")
+}
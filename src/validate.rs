@@ -0,0 +1,152 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::error::Location;
+
+/// Something wrong with the structure of a puzzle input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Violation {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl Violation {
+    /// The message, followed by the offending line with a caret under the position when one is
+    /// known.
+    pub fn diagnostic(&self) -> String {
+        match &self.location {
+            Some(location) => format!("{}\n{}", self, location.excerpt()),
+            None => self.to_string(),
+        }
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "{} at line {}, column {}",
+                self.message, location.line, location.column
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Collects every violation found in one input.
+pub(crate) struct Violations<'a> {
+    input: &'a str,
+    found: Vec<Violation>,
+}
+
+impl<'a> Violations<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Violations {
+            input,
+            found: Vec::new(),
+        }
+    }
+
+    /// Reports a violation at the start of `at`, which must be a slice of the input.
+    pub(crate) fn at(&mut self, at: &str, message: impl Into<String>) {
+        self.found.push(Violation {
+            message: message.into(),
+            location: Some(Location::of_slice(self.input, at)),
+        });
+    }
+
+    /// Reports a violation of the input as a whole.
+    pub(crate) fn whole(&mut self, message: impl Into<String>) {
+        self.found.push(Violation {
+            message: message.into(),
+            location: None,
+        });
+    }
+
+    /// Checks that `token` is a number of type `T`, describing it as `what` if it isn't.
+    pub(crate) fn number<T: FromStr>(&mut self, token: &str, what: &str) -> Option<T> {
+        let number = token.parse().ok();
+        if number.is_none() {
+            if token.is_empty() {
+                self.at(token, format!("missing {}", what));
+            } else {
+                self.at(token, format!("invalid {} {:?}", what, token));
+            }
+        }
+
+        number
+    }
+
+    /// Checks that the input is a non-empty rectangular map made of characters accepted by
+    /// `allowed`, which are described by `expected`.
+    pub(crate) fn grid(&mut self, allowed: impl Fn(char) -> bool, expected: &str) {
+        let input = self.input.trim_end();
+        if input.is_empty() {
+            self.whole("the map is empty");
+            return;
+        }
+
+        let mut width = None;
+        for line in input.lines() {
+            for (index, c) in line.char_indices() {
+                if !allowed(c) {
+                    self.at(
+                        &line[index..],
+                        format!("unexpected character {:?}, expected {}", c, expected),
+                    );
+                }
+            }
+
+            let row_width = line.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => self.at(
+                    line,
+                    format!("row has {} columns, expected {}", row_width, width),
+                ),
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// The non-empty input without trailing whitespace, or `None` after reporting that it's empty.
+    pub(crate) fn content(&mut self) -> Option<&'a str> {
+        let input = self.input.trim_end();
+        if input.is_empty() {
+            self.whole("the input is empty");
+            return None;
+        }
+
+        Some(input)
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Violation> {
+        self.found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Violations;
+
+    #[test]
+    fn test_grid_reports_every_violation() {
+        let input = "ab.\nax\nabc\n";
+        let mut violations = Violations::new(input);
+        violations.grid(|c| c.is_ascii_lowercase(), "a letter");
+
+        let found: Vec<_> = violations
+            .into_vec()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "unexpected character '.', expected a letter at line 1, column 3",
+                "row has 2 columns, expected 3 at line 2, column 1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Runs the website client against a local stand-in server.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc2024::client::{Client, Outcome, RateLimiter};
+use aoc2024::{Error, Part};
+use tiny_http::{Response, Server};
+
+#[derive(Clone, Debug)]
+struct Seen {
+    method: String,
+    url: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// A server on a free local port answering every request with `status` and `body`.
+struct StandIn {
+    server: Arc<Server>,
+    seen: Arc<Mutex<Vec<Seen>>>,
+}
+
+impl StandIn {
+    fn start(status: u16, body: &'static str) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let (server_ref, seen_ref) = (Arc::clone(&server), Arc::clone(&seen));
+        thread::spawn(move || {
+            for mut request in server_ref.incoming_requests() {
+                let mut content = String::new();
+                request.as_reader().read_to_string(&mut content).unwrap();
+
+                seen_ref.lock().unwrap().push(Seen {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie: request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string()),
+                    body: content,
+                });
+
+                let response = Response::from_string(body).with_status_code(status);
+                let _ = request.respond(response);
+            }
+        });
+
+        StandIn { server, seen }
+    }
+
+    fn url(&self) -> String {
+        format!("http://{}", self.server.server_addr().to_ip().unwrap())
+    }
+
+    fn seen(&self) -> Vec<Seen> {
+        self.seen.lock().unwrap().clone()
+    }
+}
+
+impl Drop for StandIn {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-client-{}-{}", process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn client(stand_in: &StandIn, name: &str, interval: Duration) -> Client {
+    let limiter = RateLimiter::new(temp_dir(name).join(".last-request"), interval);
+    Client::new(stand_in.url(), "abc123", limiter)
+}
+
+#[test]
+fn fetch_sends_the_session_cookie() {
+    let stand_in = StandIn::start(200, "3   4\n4   3\n");
+    let client = client(&stand_in, "fetch", Duration::ZERO);
+
+    assert_eq!(client.fetch_input(1).unwrap(), "3   4\n4   3\n");
+
+    let seen = stand_in.seen();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].method, "GET");
+    assert_eq!(seen[0].url, "/2024/day/1/input");
+    assert_eq!(seen[0].cookie.as_deref(), Some("session=abc123"));
+}
+
+#[test]
+fn submit_posts_the_answer_and_parses_the_response() {
+    let stand_in = StandIn::start(
+        200,
+        "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>",
+    );
+    let client = client(&stand_in, "submit", Duration::ZERO);
+
+    assert_eq!(client.submit(7, Part::Two, "42").unwrap(), Outcome::TooLow);
+
+    let seen = stand_in.seen();
+    assert_eq!(seen[0].method, "POST");
+    assert_eq!(seen[0].url, "/2024/day/7/answer");
+    assert_eq!(seen[0].body, "level=2&answer=42");
+}
+
+#[test]
+fn error_statuses_are_reported() {
+    let stand_in = StandIn::start(
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks!",
+    );
+    let client = client(&stand_in, "status", Duration::ZERO);
+
+    let e = client.fetch_input(25).unwrap_err();
+    assert!(matches!(e, Error::Http(_)));
+    assert!(e.to_string().contains("404"), "{}", e);
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let stand_in = StandIn::start(200, "input");
+    let client = client(&stand_in, "limit", Duration::from_millis(300));
+
+    let start = Instant::now();
+    client.fetch_input(1).unwrap();
+    client.fetch_input(2).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(stand_in.seen().len(), 2);
+}
+
+#[test]
+fn fetch_command_caches_inputs() {
+    let stand_in = StandIn::start(200, "1 2 3\n");
+    let dir = temp_dir("command");
+
+    let fetch = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["fetch", "3", "--input-dir"])
+            .arg(&dir)
+            .env("AOC_URL", stand_in.url())
+            .env("AOC_SESSION", "abc123")
+            .status()
+            .unwrap()
+    };
+
+    assert!(fetch().success());
+    assert!(fetch().success());
+
+    assert_eq!(
+        fs::read_to_string(dir.join("day03.txt")).unwrap(),
+        "1 2 3\n"
+    );
+    assert_eq!(stand_in.seen().len(), 1);
+}
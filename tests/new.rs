@@ -0,0 +1,152 @@
+//! Scaffolds a day into a copy of the days module, and into a copy of the crate that is then
+//! built, run and tested.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
+
+/// Copies `from` into `to`, recursively.
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        } else {
+            fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+#[test]
+fn new_day_is_created_and_registered() {
+    let root = env::temp_dir().join(format!("aoc-new-{}", process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src/days")).unwrap();
+    fs::copy(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days/mod.rs"),
+        root.join("src/days/mod.rs"),
+    )
+    .unwrap();
+
+    let answers = root.join("answers.toml");
+    let new = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["new", "20", "--root"])
+            .arg(&root)
+            .arg("--answers")
+            .arg(&answers)
+            .env_remove("AOC_INPUT_DIR")
+            .output()
+            .unwrap()
+    };
+
+    assert!(new().status.success());
+
+    for file in [
+        "src/days/day20/mod.rs",
+        "src/days/day20/parser.rs",
+        "src/days/day20/generator.rs",
+        "src/days/day20/validator.rs",
+        "src/days/day20/example.txt",
+        "src/bin/day20/main.rs",
+    ] {
+        assert!(root.join(file).is_file(), "{} is missing", file);
+    }
+
+    let module = fs::read_to_string(root.join("src/days/day20/mod.rs")).unwrap();
+    assert!(module.contains("pub struct Day20;"));
+    assert!(!module.contains("__DAY__"));
+
+    let days = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert!(days
+        .contains("pub const DAYS: [u32; 14] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 20];"));
+    for (line, after) in [
+        ("pub mod day20;", "pub mod day2;"),
+        ("pub use day20::Day20;", "pub use day2::Day2;"),
+        ("        20 => Some(&Day20),", "        13 => Some(&Day13),"),
+        (
+            "        20 => day20::generator::generate,",
+            "        13 => day13::generator::generate,",
+        ),
+        (
+            "        20 => day20::validator::validate,",
+            "        13 => day13::validator::validate,",
+        ),
+    ] {
+        assert!(
+            days.contains(&format!("{}\n{}\n", after, line)),
+            "{:?} is not after {:?}",
+            line,
+            after
+        );
+    }
+
+    assert_eq!(
+        fs::read_to_string(&answers).unwrap(),
+        "[day20.\"inputs/day20.txt\"]\n"
+    );
+
+    // a day that already exists is left alone
+    let again = new();
+    assert_eq!(again.status.code(), Some(2));
+    assert_eq!(
+        fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+        days
+    );
+}
+
+#[test]
+fn new_day_runs_and_passes_the_tests() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = env::temp_dir().join(format!("aoc-new-build-{}", process::id()));
+    let _ = fs::remove_dir_all(&root);
+    copy_dir(&manifest_dir.join("src"), &root.join("src"));
+    for file in ["Cargo.toml", "Cargo.lock"] {
+        fs::copy(manifest_dir.join(file), root.join(file)).unwrap();
+    }
+
+    let new = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", "20", "--root"])
+        .arg(&root)
+        .arg("--answers")
+        .arg(root.join("answers.toml"))
+        .output()
+        .unwrap();
+    assert!(new.status.success());
+
+    // runs a cargo subcommand offline and quietly. A target directory of its own keeps the
+    // dependencies built between runs, without waiting on the lock of the one running this test
+    let cargo = |args: &[&str]| {
+        Command::new(env!("CARGO"))
+            .args(&args[..1])
+            .args(["--offline", "--quiet"])
+            .args(&args[1..])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", manifest_dir.join("target/new-day"))
+            .output()
+            .unwrap()
+    };
+
+    let output = cargo(&["run", "--bin", "aoc", "--", "run", "20", "--example"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // the scaffolded parts say they aren't solved, which shows the day is registered and runs
+    assert_eq!(output.status.code(), Some(5), "{}", stderr);
+    assert!(stderr.contains("part 1 is not solved yet"), "{}", stderr);
+
+    // the tests that go through every day still pass with the new one in it
+    let output = cargo(&["test", "--lib"]);
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
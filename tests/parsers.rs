@@ -0,0 +1,103 @@
+//! Replays the fuzz corpus in `fuzz/corpus` through the parsers, along with random mutations of
+//! each entry, so that parser panics are caught on a stable toolchain without cargo-fuzz.
+//!
+//! Set `AOC_FUZZ_ITERATIONS` to try more mutations per corpus entry.
+
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use aoc2024::days;
+use aoc2024::rng::Rng;
+
+/// Fuzz targets and the day whose parser each one exercises.
+const TARGETS: [(&str, u32); 5] = [
+    ("parse_day3", 3),
+    ("parse_day5", 5),
+    ("parse_day7", 7),
+    ("parse_day9", 9),
+    ("parse_day13", 13),
+];
+
+/// Characters that mean something to at least one parser, plus a few that don't.
+const ALPHABET: &[char] = &[
+    '0', '1', '9', ' ', '\n', '\r', ',', ':', '|', '(', ')', '+', '=', '-', 'm', 'u', 'l', 'd',
+    'o', 'n', '\'', 't', 'X', 'Y', 'A', 'B', 'é', '\0',
+];
+
+fn iterations() -> usize {
+    env::var("AOC_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(500)
+}
+
+fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for _ in 0..rng.range(1..=4) {
+        let at = rng.index(chars.len() + 1);
+
+        match rng.below(6) {
+            0 => chars.insert(at, *rng.choose(ALPHABET)),
+            1 if at < chars.len() => chars[at] = *rng.choose(ALPHABET),
+            2 if at < chars.len() => {
+                chars.remove(at);
+            }
+            3 => chars.truncate(at),
+            4 => {
+                // repeat a run of digits, which is a quick way to overflow integer parsers
+                let digits: Vec<char> = (0..rng.range(10..=40)).map(|_| '9').collect();
+                chars.splice(at..at, digits);
+            }
+            _ => {
+                let end = (at + rng.index(20)).min(chars.len());
+                let span = chars[at..end].to_vec();
+                chars.splice(at..at, span);
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+#[test]
+fn parsers_do_not_panic() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus");
+
+    for (target, day) in TARGETS {
+        let solution = days::get(day).unwrap();
+
+        let mut entries: Vec<_> = fs::read_dir(corpus.join(target))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        entries.sort();
+
+        for (index, path) in entries.iter().enumerate() {
+            let seed = String::from_utf8_lossy(&fs::read(path).unwrap()).into_owned();
+            let mut rng = Rng::new(u64::from(day) << 32 | index as u64);
+
+            for iteration in 0..iterations() {
+                let input = if iteration == 0 {
+                    seed.clone()
+                } else {
+                    mutate(&mut rng, &seed)
+                };
+
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    let _ = solution.parse(&input);
+                }));
+
+                assert!(
+                    result.is_ok(),
+                    "{} panicked on a mutation of {}:\n{:?}",
+                    target,
+                    path.display(),
+                    input
+                );
+            }
+        }
+    }
+}
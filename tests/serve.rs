@@ -0,0 +1,200 @@
+//! Runs the JSON API on a local port and sends it requests.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::thread;
+
+use aoc2024::serve::{Days, Server};
+use aoc2024::{days, DynSolution, Result, Solution};
+use serde_json::Value;
+
+/// A server on a free local port, stopped when dropped.
+struct Running {
+    server: Arc<Server>,
+}
+
+impl Running {
+    fn start() -> Self {
+        Self::serving(days::get)
+    }
+
+    fn serving(days: Days) -> Self {
+        Self::with(Server::bind("127.0.0.1:0").unwrap().with_days(days))
+    }
+
+    fn with(server: Server) -> Self {
+        let server = Arc::new(server);
+
+        let server_ref = Arc::clone(&server);
+        thread::spawn(move || server_ref.run());
+
+        Running { server }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.server.addr(), path)
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
+
+/// Sends a request and returns the status and the JSON body, whatever the status.
+fn send(method: &str, url: &str, body: &str) -> (u16, Value) {
+    let response = match ureq::request(method, url).send_string(body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => panic!("{}", e),
+    };
+
+    assert_eq!(response.content_type(), "application/json");
+    let status = response.status();
+    (
+        status,
+        serde_json::from_str(&response.into_string().unwrap()).unwrap(),
+    )
+}
+
+#[test]
+fn answers_are_returned_with_timings() {
+    let running = Running::start();
+
+    let (status, body) = send(
+        "POST",
+        &running.url("/day/1/part/2"),
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    );
+
+    assert_eq!(status, 200);
+    assert_eq!(body["day"], 1);
+    assert_eq!(body["part"], 2);
+    assert_eq!(body["answer"], "31");
+    assert!(body["parse_ns"].is_u64());
+    assert!(body["solve_ns"].is_u64());
+    assert!(body["error"].is_null());
+}
+
+#[test]
+fn parse_errors_come_with_a_diagnostic() {
+    let running = Running::start();
+
+    let (status, body) = send("POST", &running.url("/day/1/part/1"), "1 2\n3 x\n");
+
+    assert_eq!(status, 422);
+    assert!(body["answer"].is_null());
+    assert_eq!(body["error"]["kind"], "parse");
+    assert_eq!(body["error"]["line"], 2);
+    assert_eq!(body["error"]["column"], 3);
+    assert!(body["diagnostic"]
+        .as_str()
+        .unwrap()
+        .ends_with("2 | 3 x\n  |   ^"));
+}
+
+#[test]
+fn bad_requests_are_rejected() {
+    let running = Running::start();
+
+    for (method, path, status) in [
+        ("GET", "/day/1/part/1", 405),
+        ("POST", "/day/25/part/1", 404),
+        ("POST", "/day/1/part/3", 404),
+        ("POST", "/", 404),
+    ] {
+        let (actual, body) = send(method, &running.url(path), "");
+        assert_eq!(actual, status, "{} {}", method, path);
+        assert_eq!(body["error"]["kind"], "request");
+    }
+}
+
+/// A day whose first part always panics.
+struct Panics;
+
+impl Solution for Panics {
+    type Input = ();
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(&self, _input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn part1(&self, _input: &()) -> Result<u32> {
+        panic!("part 1 is broken");
+    }
+
+    fn part2(&self, _input: &()) -> Result<u32> {
+        Ok(2)
+    }
+}
+
+fn with_panicking_day_99(day: u32) -> Option<&'static dyn DynSolution> {
+    match day {
+        99 => Some(&Panics),
+        _ => days::get(day),
+    }
+}
+
+#[test]
+fn panics_fail_only_their_own_request() {
+    let running = Running::serving(with_panicking_day_99);
+
+    for _ in 0..2 {
+        let (status, body) = send("POST", &running.url("/day/99/part/1"), "");
+        assert_eq!(status, 500);
+        assert_eq!(body["error"]["kind"], "panic");
+        assert_eq!(body["error"]["message"], "part 1 is broken");
+    }
+
+    let (status, body) = send("POST", &running.url("/day/99/part/2"), "");
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "2");
+
+    let (status, body) = send("POST", &running.url("/day/11/part/1"), "125 17\n");
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "55312");
+}
+
+#[test]
+fn large_inputs_are_rejected() {
+    let running = Running::with(Server::bind("127.0.0.1:0").unwrap().with_max_body(16));
+
+    let (status, body) = send("POST", &running.url("/day/11/part/1"), &"1 ".repeat(100));
+    assert_eq!(status, 413);
+    assert_eq!(body["error"]["kind"], "request");
+
+    // smaller inputs are still answered
+    let (status, body) = send("POST", &running.url("/day/11/part/1"), "125 17 125 17\n");
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "110624");
+}
+
+#[test]
+fn serve_command_prints_its_address() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["serve", "--port", "0"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut line)
+        .unwrap();
+    let address = line
+        .trim()
+        .strip_prefix("listening on ")
+        .unwrap()
+        .to_string();
+
+    let result = ureq::post(&format!("{}/day/11/part/1", address)).send_string("125 17\n");
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let body: Value = serde_json::from_str(&result.unwrap().into_string().unwrap()).unwrap();
+    assert_eq!(body["answer"], "55312");
+}
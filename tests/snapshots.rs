@@ -0,0 +1,236 @@
+//! Runs the day binaries and compares what they print with the snapshots in `tests/snapshots`.
+//!
+//! After a deliberate change to the output, run with `AOC_BLESS=1` to rewrite the snapshots, and
+//! review the difference along with the change.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Set to rewrite the snapshots from the current output instead of comparing with them.
+const BLESS_VAR: &str = "AOC_BLESS";
+
+struct Case {
+    name: &'static str,
+    binary: &'static str,
+    args: &'static [&'static str],
+    /// Sent to stdin, for inputs given as `-`.
+    stdin: &'static str,
+}
+
+const CASES: &[Case] = &[
+    Case {
+        name: "day1",
+        binary: env!("CARGO_BIN_EXE_day1"),
+        args: &["src/days/day1/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day2",
+        binary: env!("CARGO_BIN_EXE_day2"),
+        args: &["src/days/day2/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day3",
+        binary: env!("CARGO_BIN_EXE_day3"),
+        args: &["src/days/day3/example1.txt", "src/days/day3/example2.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day4",
+        binary: env!("CARGO_BIN_EXE_day4"),
+        args: &["src/days/day4/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day5",
+        binary: env!("CARGO_BIN_EXE_day5"),
+        args: &["src/days/day5/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day6",
+        binary: env!("CARGO_BIN_EXE_day6"),
+        args: &["src/days/day6/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day7",
+        binary: env!("CARGO_BIN_EXE_day7"),
+        args: &["src/days/day7/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day8",
+        binary: env!("CARGO_BIN_EXE_day8"),
+        args: &["src/days/day8/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day9",
+        binary: env!("CARGO_BIN_EXE_day9"),
+        args: &["src/days/day9/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day10",
+        binary: env!("CARGO_BIN_EXE_day10"),
+        args: &["src/days/day10/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day11",
+        binary: env!("CARGO_BIN_EXE_day11"),
+        args: &["src/days/day11/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day12",
+        binary: env!("CARGO_BIN_EXE_day12"),
+        args: &["src/days/day12/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day13",
+        binary: env!("CARGO_BIN_EXE_day13"),
+        args: &["src/days/day13/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day1-stdin",
+        binary: env!("CARGO_BIN_EXE_day1"),
+        args: &["-"],
+        stdin: "3   4\n4   3\n",
+    },
+    Case {
+        name: "day1-missing-input",
+        binary: env!("CARGO_BIN_EXE_day1"),
+        args: &["tests/snapshots/missing.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day1-invalid-id",
+        binary: env!("CARGO_BIN_EXE_day1"),
+        args: &["-"],
+        stdin: "1   2\n3   x\n",
+    },
+    Case {
+        name: "day1-part1-overflow",
+        binary: env!("CARGO_BIN_EXE_day1"),
+        args: &["-"],
+        stdin: "-2147483648   2147483647\n",
+    },
+    Case {
+        name: "day1-csv-without-bench",
+        binary: env!("CARGO_BIN_EXE_day1"),
+        args: &["--format", "csv", "src/days/day1/example.txt"],
+        stdin: "",
+    },
+    Case {
+        name: "day6-no-guard",
+        binary: env!("CARGO_BIN_EXE_day6"),
+        args: &["-"],
+        stdin: "..#\n...\n",
+    },
+    Case {
+        name: "day7-timeout",
+        binary: env!("CARGO_BIN_EXE_day7"),
+        args: &["--timeout", "100ms", "-"],
+        stdin: "1: 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2\n",
+    },
+    Case {
+        name: "day9-invalid-size",
+        binary: env!("CARGO_BIN_EXE_day9"),
+        args: &["-"],
+        stdin: "12a4\n",
+    },
+    Case {
+        name: "day13-missing-prize",
+        binary: env!("CARGO_BIN_EXE_day13"),
+        args: &["-"],
+        stdin: "Button A: X+1, Y+2\nButton B: X+3, Y+4\n",
+    },
+    Case {
+        name: "day13-invalid-prize",
+        binary: env!("CARGO_BIN_EXE_day13"),
+        args: &["-"],
+        stdin: "Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=5, Y=6\n\n\
+                Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=5, Y=six\n",
+    },
+];
+
+/// Runs a case from the repository root, describing the command, exit status and output.
+fn run(case: &Case) -> String {
+    let binary = Path::new(case.binary);
+    let mut child = Command::new(binary)
+        .args(case.args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env_remove("AOC_INPUT_DIR")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(case.stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    let name = binary.file_stem().unwrap().to_string_lossy();
+    let status = match output.status.code() {
+        Some(code) => code.to_string(),
+        None => String::from("killed by a signal"),
+    };
+
+    format!(
+        "$ {} {}\nexit status: {}\n--- stdout\n{}--- stderr\n{}",
+        name,
+        case.args.join(" "),
+        status,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
+
+#[test]
+fn binaries_match_snapshots() {
+    let bless = env::var_os(BLESS_VAR).is_some();
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let mut mismatches = Vec::new();
+
+    for case in CASES {
+        let actual = run(case);
+        let path = dir.join(format!("{}.snap", case.name));
+
+        if bless {
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => mismatches.push(format!(
+                "{} does not match\n=== expected\n{}=== actual\n{}",
+                path.display(),
+                expected,
+                actual
+            )),
+            Err(_) => mismatches.push(format!("{} is missing", path.display())),
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "{}\nrerun with {}=1 to accept the new output",
+        mismatches.join("\n"),
+        BLESS_VAR
+    );
+}